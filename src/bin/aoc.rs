@@ -0,0 +1,83 @@
+use std::{
+    env,
+    error::Error,
+    fs,
+    io::{self, Read},
+    process::ExitCode,
+};
+
+use adventofcode2023::solver::{self, Part, SolverError};
+
+const USAGE: &str = "Usage:
+    aoc <day> <part> [input]    Solve a part of a day, reading the input file or stdin when omitted or `-`
+    aoc list                    List the implemented days";
+
+fn read_input(path: Option<&str>) -> io::Result<String> {
+    match path {
+        None | Some("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path),
+    }
+}
+
+fn describe(error: &dyn Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+
+    while let Some(error) = source {
+        message.push_str(&format!(": {}", error));
+        source = error.source();
+    }
+
+    message
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        ["list"] => {
+            for puzzle in solver::PUZZLES {
+                println!("{:>2}: {}", puzzle.day, puzzle.title);
+            }
+
+            Ok(())
+        }
+        [day, part] | [day, part, _] => {
+            let day: u8 = day
+                .parse()
+                .map_err(|_| format!("Invalid day `{}`\n\n{}", day, USAGE))?;
+            let part: Part = part
+                .parse()
+                .map_err(|_: SolverError| format!("Invalid part `{}`\n\n{}", part, USAGE))?;
+            let puzzle = solver::puzzle(day).map_err(|e| describe(&e))?;
+
+            let input =
+                read_input(args.get(2).copied()).map_err(|e| format!("Can't read input: {}", e))?;
+
+            let answer = puzzle.solve(part, &input).map_err(|e| describe(&e))?;
+
+            println!("{}", answer);
+
+            Ok(())
+        }
+        _ => Err(USAGE.to_owned()),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RaceParseError {
+    #[error("Invalid input error")]
+    InvalidInputError,
+    #[error("Can't parse number error")]
+    ParseIntError(#[from] ParseIntError),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+impl Race {
+    pub fn new(time: u64, distance: u64) -> Self {
+        Self { time, distance }
+    }
+
+    fn wins(&self, hold: u64) -> bool {
+        hold as u128 * (self.time - hold) as u128 > self.distance as u128
+    }
+
+    /// Number of hold times beating the record, from the roots of `hold * (time - hold) = distance`.
+    pub fn ways_to_win(&self) -> u64 {
+        let time = self.time as u128;
+        let discriminant = match (time * time).checked_sub(4 * self.distance as u128) {
+            Some(discriminant) => discriminant,
+            None => return 0,
+        };
+
+        // The integer square root only approximates the lower root, so step onto the first win.
+        let mut lowest = ((time - discriminant.isqrt()) / 2) as u64;
+
+        while !self.wins(lowest) {
+            lowest += 1;
+
+            if lowest > self.time / 2 {
+                return 0;
+            }
+        }
+
+        while lowest > 0 && self.wins(lowest - 1) {
+            lowest -= 1;
+        }
+
+        self.time - 2 * lowest + 1
+    }
+}
+
+/// The sheet read as a single race, ignoring the spaces between the numbers.
+impl FromStr for Race {
+    type Err = RaceParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (times, distances) = parse_rows(s)?;
+
+        Ok(Race::new(
+            times.concat().parse()?,
+            distances.concat().parse()?,
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RaceSheet {
+    races: Vec<Race>,
+}
+
+impl RaceSheet {
+    pub fn new(races: Vec<Race>) -> Self {
+        Self { races }
+    }
+
+    pub fn races(&self) -> &[Race] {
+        &self.races
+    }
+
+    pub fn margin_of_error(&self) -> u64 {
+        self.races.iter().map(Race::ways_to_win).product()
+    }
+
+    /// The sheet read as a single race, joining the digits of every time and distance.
+    pub fn kerned(&self) -> Result<Race, RaceParseError> {
+        let join = |value: fn(&Race) -> u64| {
+            self.races
+                .iter()
+                .map(|race| value(race).to_string())
+                .collect::<String>()
+                .parse::<u64>()
+        };
+
+        Ok(Race::new(
+            join(|race| race.time)?,
+            join(|race| race.distance)?,
+        ))
+    }
+}
+
+impl FromStr for RaceSheet {
+    type Err = RaceParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (times, distances) = parse_rows(s)?;
+
+        let races = times
+            .iter()
+            .zip(distances.iter())
+            .map(|(time, distance)| Ok(Race::new(time.parse()?, distance.parse()?)))
+            .collect::<Result<Vec<_>, RaceParseError>>()?;
+
+        Ok(RaceSheet::new(races))
+    }
+}
+
+fn parse_rows(s: &str) -> Result<(Vec<&str>, Vec<&str>), RaceParseError> {
+    let mut lines = s.lines().filter(|line| !line.is_empty());
+
+    let times = parse_row(lines.next(), "Time:")?;
+    let distances = parse_row(lines.next(), "Distance:")?;
+
+    if times.len() != distances.len() || lines.next().is_some() {
+        return Err(RaceParseError::InvalidInputError);
+    }
+
+    Ok((times, distances))
+}
+
+fn parse_row<'a>(line: Option<&'a str>, label: &str) -> Result<Vec<&'a str>, RaceParseError> {
+    let values = line
+        .and_then(|line| line.strip_prefix(label))
+        .ok_or(RaceParseError::InvalidInputError)?;
+
+    Ok(values.split_whitespace().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn test_race_sheet_from_str() {
+        let sheet: RaceSheet = EXAMPLE.parse().expect("Should parse");
+
+        assert_eq!(
+            sheet.races(),
+            &[Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)]
+        );
+        assert_eq!(sheet.margin_of_error(), 288);
+    }
+
+    #[test]
+    fn test_race_from_str_kerned() {
+        let race: Race = EXAMPLE.parse().expect("Should parse");
+
+        assert_eq!(race, Race::new(71530, 940200));
+        assert_eq!(race.ways_to_win(), 71503);
+    }
+
+    #[test]
+    fn test_race_sheet_kerned() {
+        let sheet: RaceSheet = EXAMPLE.parse().expect("Should parse");
+
+        assert_eq!(
+            sheet.kerned().expect("Should fit"),
+            Race::new(71530, 940200)
+        );
+
+        let sheet: RaceSheet = "Time: 71 15 30 40\nDistance: 900 400 200 100000000000000"
+            .parse()
+            .expect("Should parse");
+
+        assert!(matches!(
+            sheet.kerned(),
+            Err(RaceParseError::ParseIntError(_))
+        ));
+    }
+
+    #[test]
+    fn test_race_ways_to_win_exact_roots() {
+        // Holding 10 or 20 exactly ties the record, which doesn't count as a win.
+        assert_eq!(Race::new(30, 200).ways_to_win(), 9);
+        assert_eq!(Race::new(4, 4).ways_to_win(), 0);
+        assert_eq!(Race::new(4, 3).ways_to_win(), 1);
+        assert_eq!(Race::new(1, 0).ways_to_win(), 0);
+        assert_eq!(Race::new(0, 0).ways_to_win(), 0);
+    }
+
+    #[test]
+    fn test_race_ways_to_win_brute_force() {
+        for time in 0..60 {
+            for distance in 0..1000 {
+                let race = Race::new(time, distance);
+                let expected = (0..=time).filter(|hold| race.wins(*hold)).count() as u64;
+
+                assert_eq!(race.ways_to_win(), expected, "{:?}", race);
+            }
+        }
+    }
+
+    #[test]
+    fn test_race_ways_to_win_huge() {
+        let race = Race::new(u64::MAX, u64::MAX);
+
+        assert_eq!(race.ways_to_win(), u64::MAX - 3);
+        assert_eq!(Race::new(u64::MAX, 0).ways_to_win(), u64::MAX - 1);
+    }
+
+    #[test]
+    fn test_race_sheet_mismatched_rows() {
+        assert!("Time: 1 2\nDistance: 3".parse::<RaceSheet>().is_err());
+        assert!("Distance: 3\nTime: 1".parse::<RaceSheet>().is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use thiserror::Error;
+
 pub struct CalibrationValue {
     raw_value: String,
     string_value: String,
@@ -67,57 +69,70 @@ fn find_first_number_or_string_number_in_string(value: &String, reverse: bool) -
             result = value.chars().find(|c| c.is_ascii_digit());
         }
     }
-    
+
     result
 }
 
 impl CalibrationValue {
     pub fn new(raw_value: String) -> CalibrationValue {
-        let raw_first_number =
-            find_first_number_in_string(&raw_value).expect("Should have found a number");
-        let raw_second_number =
-            find_first_number_in_string(&raw_value.chars().rev().collect::<String>())
-                .expect("Should have found a number");
+        CalibrationValue::try_new(raw_value).expect("Should have found a number")
+    }
 
-        let mut string_value = String::new();
-        string_value.push(raw_first_number);
-        string_value.push(raw_second_number);
+    pub fn new_v2(raw_value: String) -> CalibrationValue {
+        CalibrationValue::try_new_v2(raw_value).expect("Should have found a number")
+    }
 
-        let value = string_value
-            .parse::<usize>()
-            .expect("Value should be parsed to usize");
+    /// The line the value was read from.
+    pub fn raw_value(&self) -> &str {
+        &self.raw_value
+    }
 
-        CalibrationValue {
-            raw_value,
-            string_value,
-            value,
-        }
+    /// The first and last digits found on the line, before being parsed.
+    pub fn string_value(&self) -> &str {
+        &self.string_value
     }
 
-    pub fn new_v2(raw_value: String) -> CalibrationValue {
-        let raw_first_number = find_first_number_or_string_number_in_string(&raw_value, false)
-            .expect("Should have found a number");
-        let raw_second_number = find_first_number_or_string_number_in_string(&raw_value, true)
-            .expect("Should have found a number");
+    /// Like [`CalibrationValue::new`], failing on lines without any digit.
+    pub fn try_new(raw_value: String) -> Result<CalibrationValue, CalibrationValueFromStrError> {
+        let raw_first_number = find_first_number_in_string(&raw_value);
+        let raw_second_number =
+            find_first_number_in_string(&raw_value.chars().rev().collect::<String>());
 
+        CalibrationValue::from_numbers(raw_value, raw_first_number, raw_second_number)
+    }
+
+    /// Like [`CalibrationValue::new_v2`], failing on lines without any digit or digit word.
+    pub fn try_new_v2(raw_value: String) -> Result<CalibrationValue, CalibrationValueFromStrError> {
+        let raw_first_number = find_first_number_or_string_number_in_string(&raw_value, false);
+        let raw_second_number = find_first_number_or_string_number_in_string(&raw_value, true);
+
+        CalibrationValue::from_numbers(raw_value, raw_first_number, raw_second_number)
+    }
+
+    fn from_numbers(
+        raw_value: String,
+        raw_first_number: Option<char>,
+        raw_second_number: Option<char>,
+    ) -> Result<CalibrationValue, CalibrationValueFromStrError> {
         let mut string_value = String::new();
-        string_value.push(raw_first_number);
-        string_value.push(raw_second_number);
+        string_value.push(raw_first_number.ok_or(CalibrationValueFromStrError::Generic())?);
+        string_value.push(raw_second_number.ok_or(CalibrationValueFromStrError::Generic())?);
 
         let value = string_value
             .parse::<usize>()
-            .expect("Value should be parsed to usize");
+            .map_err(|_| CalibrationValueFromStrError::Generic())?;
 
-        CalibrationValue {
+        Ok(CalibrationValue {
             raw_value,
             string_value,
             value,
-        }
+        })
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum CalibrationValueFromStrError {
+    #[error("Invalid input error")]
     Generic(),
 }
 
@@ -125,6 +140,6 @@ impl FromStr for CalibrationValue {
     type Err = CalibrationValueFromStrError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(CalibrationValue::new(s.to_string()))
+        CalibrationValue::try_new(s.to_string())
     }
 }
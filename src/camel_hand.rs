@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    fmt,
     num::{ParseIntError, TryFromIntError},
 };
 
@@ -15,13 +15,13 @@ pub enum CamelHandParseError {
     TryFromIntError(#[from] TryFromIntError),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum GameType {
     WithJoker,
     WithoutJoker,
 }
 
-#[derive(Debug, PartialEq, Ord, PartialOrd, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Ord, PartialOrd, Eq, Hash)]
 pub enum CamelCard {
     Joker,
     Two,
@@ -63,7 +63,148 @@ impl TryFrom<(char, &GameType)> for CamelCard {
     }
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Ord, Eq)]
+impl fmt::Display for CamelCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            CamelCard::Ace => 'A',
+            CamelCard::King => 'K',
+            CamelCard::Queen => 'Q',
+            CamelCard::Jack | CamelCard::Joker => 'J',
+            CamelCard::Ten => 'T',
+            CamelCard::Nine => '9',
+            CamelCard::Eight => '8',
+            CamelCard::Seven => '7',
+            CamelCard::Six => '6',
+            CamelCard::Five => '5',
+            CamelCard::Four => '4',
+            CamelCard::Three => '3',
+            CamelCard::Two => '2',
+        };
+
+        write!(f, "{}", symbol)
+    }
+}
+
+/// Rank held by wild cards when breaking ties between hands of the same type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WildRank {
+    /// Below every other card, like the joker.
+    Lowest,
+    /// Wherever the card sits in the ordering.
+    Natural,
+    /// Above every other card.
+    Highest,
+}
+
+/// Describes how hands are played: the card ordering from lowest to highest, which cards are
+/// wild and how wild cards rank for tie-breaking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CamelRules {
+    order: Vec<CamelCard>,
+    wild: Vec<CamelCard>,
+    wild_rank: WildRank,
+}
+
+impl CamelRules {
+    const STANDARD_ORDER: &'static str = "23456789TJQKA";
+
+    /// Builds rules from card characters. `order` must list every card exactly once, lowest
+    /// first, and `wild` may only name cards from it.
+    pub fn new(order: &str, wild: &str, wild_rank: WildRank) -> Result<Self, CamelHandParseError> {
+        let parse = |cards: &str| {
+            cards
+                .chars()
+                .map(|c| CamelCard::try_from((c, &GameType::WithoutJoker)))
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        let order = parse(order)?;
+        let wild = parse(wild)?;
+
+        let mut standard = parse(Self::STANDARD_ORDER)?;
+        let mut sorted = order.clone();
+        standard.sort();
+        sorted.sort();
+
+        if sorted != standard {
+            return Err(CamelHandParseError::InvalidInputError);
+        }
+
+        Ok(Self {
+            order,
+            wild,
+            wild_rank,
+        })
+    }
+
+    /// The card a character stands for. A wild `J` is the joker, like with
+    /// [`GameType::WithJoker`], every other character is its natural card.
+    pub fn card(&self, c: char) -> Result<CamelCard, CamelHandParseError> {
+        let game_type = match self.wild.contains(&CamelCard::Jack) {
+            true => GameType::WithJoker,
+            false => GameType::WithoutJoker,
+        };
+
+        CamelCard::try_from((c, &game_type))
+    }
+
+    pub fn is_wild(&self, card: &CamelCard) -> bool {
+        self.wild.contains(&Self::natural(card))
+    }
+
+    /// The card wild cards stand for among `cards`: the largest group of natural cards, the
+    /// highest ranked one on ties, or the highest ranked natural card when every card is wild.
+    pub fn substitute(&self, cards: &[CamelCard]) -> CamelCard {
+        let natural = cards.iter().filter(|card| !self.is_wild(card));
+
+        natural
+            .clone()
+            .max_by_key(|card| {
+                let count = natural.clone().filter(|c| c == card).count();
+
+                (count, self.rank(card))
+            })
+            .or_else(|| self.order.iter().rev().find(|card| !self.is_wild(card)))
+            .or(self.order.last())
+            .copied()
+            .expect("Should have a card order")
+    }
+
+    /// The joker is a wild jack, anything else is already natural.
+    fn natural(card: &CamelCard) -> CamelCard {
+        match card {
+            CamelCard::Joker => CamelCard::Jack,
+            card => *card,
+        }
+    }
+
+    /// Tie-breaking rank of `card`, from 1 for the lowest card of the ordering up to 13, with
+    /// 0 and 14 left for wild cards ranking lowest or highest.
+    pub fn rank(&self, card: &CamelCard) -> u8 {
+        match self.wild_rank {
+            WildRank::Lowest if self.is_wild(card) => 0,
+            WildRank::Highest if self.is_wild(card) => 14,
+            _ => self
+                .order
+                .iter()
+                .position(|c| *c == Self::natural(card))
+                .map_or(0, |position| position as u8 + 1),
+        }
+    }
+}
+
+impl From<&GameType> for CamelRules {
+    fn from(game_type: &GameType) -> Self {
+        let wild = match game_type {
+            GameType::WithJoker => "J",
+            GameType::WithoutJoker => "",
+        };
+
+        Self::new(Self::STANDARD_ORDER, wild, WildRank::Lowest).expect("Should be valid rules")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq)]
 pub enum CamelHandType {
     HighCard,
     OnePair,
@@ -74,91 +215,297 @@ pub enum CamelHandType {
     FiveOfAKind,
 }
 
-#[derive(Debug, PartialEq, Ord, Eq)]
+impl CamelHandType {
+    /// Classifies cards from how many times each of them appears, wild cards joining the
+    /// largest group since that always makes the strongest hand.
+    fn classify(cards: &[CamelCard], wild: &[bool]) -> Self {
+        let mut histogram = [0usize; 14];
+        let mut wild_count = 0;
+
+        for (card, wild) in cards.iter().zip(wild.iter()) {
+            match wild {
+                true => wild_count += 1,
+                false => histogram[*card as usize] += 1,
+            }
+        }
+
+        let mut counts: Vec<usize> = histogram.into_iter().filter(|count| *count > 0).collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+
+        match counts.first_mut() {
+            Some(largest) => *largest += wild_count,
+            None => counts.push(wild_count),
+        }
+
+        match counts.as_slice() {
+            [5, ..] => CamelHandType::FiveOfAKind,
+            [4, ..] => CamelHandType::FourOfAKind,
+            [3, 2, ..] => CamelHandType::FullHouse,
+            [3, ..] => CamelHandType::ThreeOfAKind,
+            [2, 2, ..] => CamelHandType::TwoPair,
+            [2, ..] => CamelHandType::OnePair,
+            _ => CamelHandType::HighCard,
+        }
+    }
+}
+
+/// A hand and its bid. Hands compare by their packed [`CamelHand::key`], so two hands are
+/// equal when they rank the same, whatever their bids.
+#[derive(Debug)]
 pub struct CamelHand {
-    cards: Vec<CamelCard>,
+    cards: [CamelCard; CamelHand::SIZE],
+    wild: [bool; CamelHand::SIZE],
+    substitute: CamelCard,
+    key: u32,
     pub bid: usize,
     pub strength: CamelHandType,
 }
 
-impl TryFrom<(&str, GameType)> for CamelHand {
-    type Error = CamelHandParseError;
+impl CamelHand {
+    pub const SIZE: usize = 5;
 
-    fn try_from((value, game_type): (&str, GameType)) -> Result<Self, Self::Error> {
-        let (hand, bid) = value
-            .split_once(' ')
-            .ok_or(CamelHandParseError::InvalidInputError)?;
+    /// Packs the type above the tie-breaking rank of every card, one nibble each from the
+    /// first card down, so hands sort as plain integers.
+    fn pack(strength: CamelHandType, ranks: impl Iterator<Item = u8>) -> u32 {
+        ranks.fold(strength as u32, |key, rank| key << 4 | rank as u32)
+    }
 
-        let cards: Vec<CamelCard> = hand
-            .chars()
-            .map(|c| (c, &game_type))
-            .map(CamelCard::try_from)
-            .collect::<Result<Vec<_>, _>>()?;
+    /// Sort key of the hand: the type in the high bits and a nibble per card rank below it.
+    pub fn key(&self) -> u32 {
+        self.key
+    }
 
-        let mut card_count: HashMap<&CamelCard, (usize, &CamelCard)> = HashMap::new();
-        let joker_count = cards.iter().filter(|c| c == &&CamelCard::Joker).count();
+    fn ranks(&self) -> [u8; CamelHand::SIZE] {
+        std::array::from_fn(|position| {
+            (self.key >> (4 * (CamelHand::SIZE - 1 - position)) & 0xF) as u8
+        })
+    }
 
-        for card in cards.iter() {
-            if card_count.contains_key(card.to_owned()) {
-                continue;
-            }
-            let count = cards.iter().filter(|c| c == &card).count();
+    /// Reports the detected type and what every wild card stood for to reach it, as chosen
+    /// by [`CamelRules::substitute`].
+    pub fn explain(&self) -> HandExplanation {
+        let substitutions = self
+            .cards
+            .iter()
+            .zip(self.wild.iter())
+            .enumerate()
+            .filter(|(_, (_, wild))| **wild)
+            .map(|(position, (card, _))| Substitution {
+                position,
+                wild: *card,
+                card: self.substitute,
+            })
+            .collect();
+
+        HandExplanation {
+            strength: self.strength,
+            substitutions,
+        }
+    }
 
-            card_count.insert(card, (count, &card));
+    /// Explains how this hand compares with `other`: by type, by the first card position
+    /// whose ranks differ, or not at all when both hands rank the same.
+    pub fn explain_against(&self, other: &CamelHand) -> HandComparison {
+        let decided_by = if self.strength != other.strength {
+            TieBreak::Type
+        } else {
+            self.ranks()
+                .iter()
+                .zip(other.ranks().iter())
+                .position(|(rank, other)| rank != other)
+                .map_or(TieBreak::Identical, TieBreak::Card)
+        };
+
+        HandComparison {
+            ordering: self.cmp(other),
+            decided_by,
         }
+    }
+}
+
+/// Why a hand has the type it has, see [`CamelHand::explain`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandExplanation {
+    pub strength: CamelHandType,
+    pub substitutions: Vec<Substitution>,
+}
+
+/// A wild card at `position` standing for `card`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Substitution {
+    pub position: usize,
+    pub wild: CamelCard,
+    pub card: CamelCard,
+}
 
-        let mut counted_cards: Vec<(usize, &CamelCard)> =
-            card_count.into_values().collect::<Vec<_>>();
-        counted_cards.sort_by(|a, b| b.0.cmp(&a.0));
+/// How two hands compare, see [`CamelHand::explain_against`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandComparison {
+    pub ordering: std::cmp::Ordering,
+    pub decided_by: TieBreak,
+}
 
-        if joker_count > 0 && joker_count < 5 {
-            let mut joker_index: Option<usize> = None;
-            for (i, counted_card) in counted_cards.iter_mut().enumerate() {
-                if counted_card.1 == &CamelCard::Joker {
-                    joker_index = Some(i);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The hands have different types.
+    Type,
+    /// The types are equal and the cards first differ at this position.
+    Card(usize),
+    /// Every card ranks the same, so neither hand wins.
+    Identical,
+}
 
-                    continue;
-                }
+impl TryFrom<(&str, GameType)> for CamelHand {
+    type Error = CamelHandParseError;
 
-                counted_card.0 += joker_count;
+    fn try_from((value, game_type): (&str, GameType)) -> Result<Self, Self::Error> {
+        CamelHand::try_from((value, &CamelRules::from(&game_type)))
+    }
+}
 
-                break;
-            }
+impl TryFrom<(&str, &CamelRules)> for CamelHand {
+    type Error = CamelHandParseError;
 
-            if let Some(i) = joker_index {
-                counted_cards.remove(i);
+    fn try_from((value, rules): (&str, &CamelRules)) -> Result<Self, Self::Error> {
+        let (hand, bid) = value
+            .split_once(' ')
+            .ok_or(CamelHandParseError::InvalidInputError)?;
 
-            }
-        }
+        let cards: [CamelCard; CamelHand::SIZE] = hand
+            .chars()
+            .map(|c| rules.card(c))
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .map_err(|_| CamelHandParseError::InvalidInputError)?;
 
-        let strength = match counted_cards.as_slice() {
-            [(5, _), ..] => Ok(CamelHandType::FiveOfAKind),
-            [(4, _), ..] => Ok(CamelHandType::FourOfAKind),
-            [(3, _), (2, _), ..] => Ok(CamelHandType::FullHouse),
-            [(3, _), ..] => Ok(CamelHandType::ThreeOfAKind),
-            [(2, _), (2, _), ..] => Ok(CamelHandType::TwoPair),
-            [(2, _), ..] => Ok(CamelHandType::OnePair),
-            [(1, _), ..] => Ok(CamelHandType::HighCard),
-            _ => Err(CamelHandParseError::InvalidInputError),
-        }?;
+        let wild = cards.map(|card| rules.is_wild(&card));
+        let strength = CamelHandType::classify(&cards, &wild);
+        let key = CamelHand::pack(strength, cards.iter().map(|card| rules.rank(card)));
 
         Ok(CamelHand {
             cards,
+            wild,
+            substitute: rules.substitute(&cards),
+            key,
             bid: bid.parse()?,
             strength,
         })
     }
 }
 
+/// The hand in puzzle notation, like `KK677 28`.
+impl fmt::Display for CamelHand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for card in self.cards.iter() {
+            write!(f, "{}", card)?;
+        }
+
+        write!(f, " {}", self.bid)
+    }
+}
+
+impl PartialEq for CamelHand {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for CamelHand {}
+
+impl Ord for CamelHand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
 impl PartialOrd for CamelHand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        let result = self.strength.partial_cmp(&other.strength)?;
+        Some(self.cmp(other))
+    }
+}
+
+/// Every hand of a game, ranked from the weakest to the strongest.
+#[derive(Debug)]
+pub struct CamelTable {
+    hands: Vec<CamelHand>,
+    ranking: Vec<usize>,
+    rank_of: Vec<usize>,
+}
+
+impl CamelTable {
+    pub fn new(hands: Vec<CamelHand>) -> Self {
+        let mut ranking = (0..hands.len()).collect::<Vec<_>>();
+        ranking.sort_by_key(|i| hands[*i].key());
+
+        let mut rank_of = vec![0; hands.len()];
+        for (position, index) in ranking.iter().enumerate() {
+            rank_of[*index] = position + 1;
+        }
 
-        if result == std::cmp::Ordering::Equal {
-            return self.cards.partial_cmp(&other.cards);
+        Self {
+            hands,
+            ranking,
+            rank_of,
         }
+    }
+
+    /// Hands in the order they were dealt.
+    pub fn hands(&self) -> &[CamelHand] {
+        &self.hands
+    }
+
+    /// Hands from the weakest to the strongest.
+    pub fn ranking(&self) -> impl Iterator<Item = &CamelHand> + '_ {
+        self.ranking.iter().map(|i| &self.hands[*i])
+    }
+
+    /// Rank of the `index`th dealt hand, starting from 1 for the weakest.
+    pub fn rank(&self, index: usize) -> Option<usize> {
+        self.rank_of.get(index).copied()
+    }
+
+    pub fn total_winnings(&self) -> usize {
+        self.ranking()
+            .enumerate()
+            .map(|(i, hand)| (i + 1) * hand.bid)
+            .sum()
+    }
+
+    /// Groups of dealt hand indices that rank exactly the same, which makes their order in the
+    /// ranking, and so the total winnings, ambiguous when their bids differ.
+    pub fn ties(&self) -> Vec<Vec<usize>> {
+        self.ranking
+            .chunk_by(|a, b| self.hands[*a].cmp(&self.hands[*b]).is_eq())
+            .filter(|group| group.len() > 1)
+            .map(|group| {
+                let mut group = group.to_vec();
+                group.sort();
+
+                group
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<(&str, GameType)> for CamelTable {
+    type Error = CamelHandParseError;
+
+    fn try_from((value, game_type): (&str, GameType)) -> Result<Self, Self::Error> {
+        CamelTable::try_from((value, &CamelRules::from(&game_type)))
+    }
+}
+
+impl TryFrom<(&str, &CamelRules)> for CamelTable {
+    type Error = CamelHandParseError;
+
+    fn try_from((value, rules): (&str, &CamelRules)) -> Result<Self, Self::Error> {
+        let hands = value
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| CamelHand::try_from((line, rules)))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Some(result)
+        Ok(CamelTable::new(hands))
     }
 }
 
@@ -235,4 +582,402 @@ mod tests {
 
         assert_eq!(hand.strength, CamelHandType::FourOfAKind);
     }
+
+    #[test]
+    fn test_camel_rules_from_game_type() {
+        let rules = CamelRules::from(&GameType::WithJoker);
+
+        assert_eq!(rules.card('J').expect("Should parse"), CamelCard::Joker);
+        assert!(rules.is_wild(&CamelCard::Joker));
+        assert!(rules.is_wild(&CamelCard::Jack));
+        assert!(!rules.is_wild(&CamelCard::Queen));
+        assert_eq!(rules.rank(&CamelCard::Joker), 0);
+        assert_eq!(rules.rank(&CamelCard::Jack), 0);
+        assert_eq!(rules.rank(&CamelCard::Two), 1);
+        assert_eq!(rules.rank(&CamelCard::Ace), 13);
+
+        let rules = CamelRules::from(&GameType::WithoutJoker);
+
+        assert_eq!(rules.card('J').expect("Should parse"), CamelCard::Jack);
+        assert!(!rules.is_wild(&CamelCard::Jack));
+        assert_eq!(rules.rank(&CamelCard::Jack), 10);
+
+        let hand: CamelHand = ("JKKJA 765", GameType::WithJoker)
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(hand.cards[0], CamelCard::Joker);
+        assert_eq!(hand.cards[3], CamelCard::Joker);
+    }
+
+    #[test]
+    fn test_camel_rules_invalid() {
+        assert!(CamelRules::new("23456789TJQK", "", WildRank::Lowest).is_err());
+        assert!(CamelRules::new("223456789TJQKA", "", WildRank::Lowest).is_err());
+        assert!(CamelRules::new("23456789TJQKA", "X", WildRank::Lowest).is_err());
+        assert!(CamelRules::new("AKQJT98765432", "2", WildRank::Natural).is_ok());
+    }
+
+    #[test]
+    fn test_camel_hand_two_wild_cards() {
+        let rules =
+            CamelRules::new("23456789TJQKA", "J2", WildRank::Lowest).expect("Should be valid");
+
+        let hand: CamelHand = ("J2KA3 1", &rules).try_into().expect("Should parse");
+        assert_eq!(hand.strength, CamelHandType::ThreeOfAKind);
+
+        let hand: CamelHand = ("J2J22 1", &rules).try_into().expect("Should parse");
+        assert_eq!(hand.strength, CamelHandType::FiveOfAKind);
+        assert_eq!(hand.ranks(), [0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_camel_hand_wild_rank() {
+        let hand = |rules: &CamelRules, hand: &str| -> CamelHand {
+            (hand, rules).try_into().expect("Should parse")
+        };
+
+        let lowest = CamelRules::new("23456789TJQKA", "J", WildRank::Lowest).expect("Valid");
+        let natural = CamelRules::new("23456789TJQKA", "J", WildRank::Natural).expect("Valid");
+        let highest = CamelRules::new("23456789TJQKA", "J", WildRank::Highest).expect("Valid");
+
+        assert!(hand(&lowest, "JKKK2 1") < hand(&lowest, "QQQQ2 1"));
+        assert!(hand(&natural, "JKKK2 1") < hand(&natural, "QQQQ2 1"));
+        assert!(hand(&natural, "JKKK2 1") > hand(&natural, "TTTT2 1"));
+        assert!(hand(&highest, "JKKK2 1") > hand(&highest, "AAAA2 1"));
+    }
+
+    #[test]
+    fn test_camel_hand_custom_order() {
+        let rules = CamelRules::new("AKQJT98765432", "", WildRank::Natural).expect("Valid");
+
+        let twos: CamelHand = ("22345 1", &rules).try_into().expect("Should parse");
+        let aces: CamelHand = ("AA345 1", &rules).try_into().expect("Should parse");
+
+        assert!(twos > aces);
+    }
+
+    #[test]
+    fn test_camel_hand_size() {
+        assert!(CamelHand::try_from(("KK67 28", GameType::WithoutJoker)).is_err());
+        assert!(CamelHand::try_from(("KK6777 28", GameType::WithoutJoker)).is_err());
+        assert!(CamelHand::try_from((" 28", GameType::WithJoker)).is_err());
+    }
+
+    #[test]
+    fn test_camel_hand_all_jokers() {
+        let hand: CamelHand = ("JJJJJ 1", GameType::WithJoker)
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(hand.strength, CamelHandType::FiveOfAKind);
+    }
+
+    /// Classifies concrete cards by counting how many of the others match each of them.
+    fn reference_type(cards: &[char]) -> CamelHandType {
+        let mut matches = [0; 5];
+
+        for (i, card) in cards.iter().enumerate() {
+            matches[i] = cards.iter().filter(|c| *c == card).count();
+        }
+        matches.sort_unstable();
+
+        match matches {
+            [5, 5, 5, 5, 5] => CamelHandType::FiveOfAKind,
+            [1, 4, 4, 4, 4] => CamelHandType::FourOfAKind,
+            [2, 2, 3, 3, 3] => CamelHandType::FullHouse,
+            [1, 1, 3, 3, 3] => CamelHandType::ThreeOfAKind,
+            [1, 2, 2, 2, 2] => CamelHandType::TwoPair,
+            [1, 1, 1, 2, 2] => CamelHandType::OnePair,
+            _ => CamelHandType::HighCard,
+        }
+    }
+
+    /// Strongest type reachable by substituting every joker with every possible card.
+    fn reference_joker_type(cards: &mut [char], from: usize) -> CamelHandType {
+        match cards[from..].iter().position(|c| *c == 'J') {
+            None => reference_type(cards),
+            Some(offset) => {
+                let position = from + offset;
+
+                let strongest = CARDS
+                    .iter()
+                    .map(|card| {
+                        cards[position] = *card;
+
+                        reference_joker_type(cards, position + 1)
+                    })
+                    .max()
+                    .expect("Should have cards");
+
+                cards[position] = 'J';
+
+                strongest
+            }
+        }
+    }
+
+    const CARDS: [char; 13] = [
+        '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+    ];
+
+    #[test]
+    fn test_camel_hand_type_exhaustive() {
+        for game_type in [GameType::WithoutJoker, GameType::WithJoker] {
+            let rules = CamelRules::from(&game_type);
+
+            for i in 0..CARDS.len().pow(5) {
+                let mut cards = (0..5)
+                    .map(|position| CARDS[i / CARDS.len().pow(position) % CARDS.len()])
+                    .collect::<Vec<_>>();
+                let hand = format!("{} 0", cards.iter().collect::<String>());
+
+                let expected = match game_type {
+                    GameType::WithoutJoker => reference_type(&cards),
+                    GameType::WithJoker => reference_joker_type(&mut cards, 0),
+                };
+
+                let hand: CamelHand = (hand.as_str(), &rules).try_into().expect("Should parse");
+
+                assert_eq!(hand.strength, expected, "{:?}", cards);
+            }
+        }
+    }
+
+    #[test]
+    fn test_camel_hand_explain() {
+        let hand: CamelHand = ("T55J5 684", GameType::WithJoker)
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(
+            hand.explain(),
+            HandExplanation {
+                strength: CamelHandType::FourOfAKind,
+                substitutions: vec![Substitution {
+                    position: 3,
+                    wild: CamelCard::Joker,
+                    card: CamelCard::Five,
+                }],
+            }
+        );
+
+        let hand: CamelHand = ("KJ2JQ 1", GameType::WithJoker)
+            .try_into()
+            .expect("Should parse");
+        let explanation = hand.explain();
+
+        assert_eq!(explanation.strength, CamelHandType::ThreeOfAKind);
+        assert!(explanation
+            .substitutions
+            .iter()
+            .all(|substitution| substitution.card == CamelCard::King));
+
+        let hand: CamelHand = ("JJJJJ 1", GameType::WithJoker)
+            .try_into()
+            .expect("Should parse");
+        let explanation = hand.explain();
+
+        assert_eq!(explanation.strength, CamelHandType::FiveOfAKind);
+        assert_eq!(explanation.substitutions.len(), 5);
+        assert!(explanation.substitutions.iter().all(|substitution| {
+            substitution.wild == CamelCard::Joker && substitution.card == CamelCard::Ace
+        }));
+
+        let rules = CamelRules::new("23456789TJQKA", "AJ", WildRank::Lowest).expect("Valid");
+        let hand: CamelHand = ("AJAJA 1", &rules).try_into().expect("Should parse");
+
+        assert!(hand
+            .explain()
+            .substitutions
+            .iter()
+            .all(|substitution| substitution.card == CamelCard::King));
+
+        let hand: CamelHand = ("T55J5 684", GameType::WithoutJoker)
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(hand.explain().strength, CamelHandType::ThreeOfAKind);
+        assert!(hand.explain().substitutions.is_empty());
+    }
+
+    #[test]
+    fn test_camel_hand_explain_against() {
+        let hand = |hand: &str| -> CamelHand {
+            (hand, GameType::WithJoker)
+                .try_into()
+                .expect("Should parse")
+        };
+
+        assert_eq!(
+            hand("KTJJT 220").explain_against(&hand("QQQJA 483")),
+            HandComparison {
+                ordering: std::cmp::Ordering::Greater,
+                decided_by: TieBreak::Card(0),
+            }
+        );
+        assert_eq!(
+            hand("32T3K 765").explain_against(&hand("KK677 28")),
+            HandComparison {
+                ordering: std::cmp::Ordering::Less,
+                decided_by: TieBreak::Type,
+            }
+        );
+        assert_eq!(
+            hand("QQQJA 483").explain_against(&hand("QQQJ2 1")),
+            HandComparison {
+                ordering: std::cmp::Ordering::Greater,
+                decided_by: TieBreak::Card(4),
+            }
+        );
+        assert_eq!(
+            hand("QQQJA 483")
+                .explain_against(&hand("QQQJA 1"))
+                .decided_by,
+            TieBreak::Identical
+        );
+    }
+
+    const EXAMPLE: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn test_camel_table_total_winnings() {
+        let table: CamelTable = (EXAMPLE, GameType::WithoutJoker)
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(table.total_winnings(), 6440);
+        assert_eq!(
+            table.ranking().map(|hand| hand.bid).collect::<Vec<_>>(),
+            vec![765, 220, 28, 684, 483]
+        );
+        assert_eq!(
+            (0..5).map(|i| table.rank(i)).collect::<Vec<_>>(),
+            vec![Some(1), Some(4), Some(3), Some(2), Some(5)]
+        );
+        assert_eq!(table.rank(5), None);
+        assert!(table.ties().is_empty());
+
+        let table: CamelTable = (EXAMPLE, GameType::WithJoker)
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(table.total_winnings(), 5905);
+        assert_eq!(table.rank(3), Some(5));
+    }
+
+    #[test]
+    fn test_camel_table_ties() {
+        let table: CamelTable = (
+            "KK677 1\n32T3K 2\nKK677 3\n22345 4\nKK677 5",
+            GameType::WithoutJoker,
+        )
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(table.ties(), vec![vec![0, 2, 4]]);
+        assert!(table.rank(0) > table.rank(1));
+
+        let rules = CamelRules::new("23456789TJQKA", "J2", WildRank::Lowest).expect("Valid");
+        let table: CamelTable = ("J3456 1\n23456 2\n33456 3", &rules)
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(table.ties(), vec![vec![0, 1]]);
+    }
+
+    #[test]
+    fn test_camel_hand_key() {
+        let hand: CamelHand = ("KTJJT 220", GameType::WithJoker)
+            .try_into()
+            .expect("Should parse");
+
+        // Four of a kind, then K, T, J, J, T with the jokers ranking lowest.
+        assert_eq!(hand.key(), 0x5C9009);
+        assert_eq!(hand.ranks(), [12, 9, 0, 0, 9]);
+        assert_eq!(
+            hand,
+            CamelHand::try_from(("KTJJT 1", GameType::WithJoker)).expect("Should parse")
+        );
+    }
+
+    #[test]
+    fn test_camel_hand_key_order() {
+        for game_type in [GameType::WithoutJoker, GameType::WithJoker] {
+            let rules = CamelRules::from(&game_type);
+
+            // Every 31st hand of the enumeration, spread over all types and card positions.
+            let mut hands = (0..CARDS.len().pow(5))
+                .step_by(31)
+                .map(|i| {
+                    let cards =
+                        (0..5).map(|position| CARDS[i / CARDS.len().pow(position) % CARDS.len()]);
+
+                    format!("{} {}", cards.collect::<String>(), i % 1000)
+                })
+                .map(|hand| CamelHand::try_from((hand.as_str(), &rules)))
+                .collect::<Result<Vec<_>, _>>()
+                .expect("Should parse");
+
+            // Compares the type, then the rank of every card as looked up from the rules.
+            let reference = |hand: &CamelHand| (hand.strength, hand.cards.map(|c| rules.rank(&c)));
+
+            hands.sort_unstable_by_key(CamelHand::key);
+
+            for pair in hands.windows(2) {
+                let [weaker, stronger] = pair else {
+                    unreachable!()
+                };
+
+                assert_eq!(
+                    weaker.key().cmp(&stronger.key()),
+                    reference(weaker).cmp(&reference(stronger)),
+                    "{:?} {:?}",
+                    weaker,
+                    stronger
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_camel_card_display() {
+        let cards = CARDS
+            .iter()
+            .map(|c| CamelCard::try_from((*c, &GameType::WithoutJoker)))
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Should parse");
+
+        assert_eq!(
+            cards
+                .iter()
+                .map(|card| card.to_string())
+                .collect::<String>(),
+            "23456789TJQKA"
+        );
+        assert_eq!(CamelCard::Joker.to_string(), "J");
+    }
+
+    #[test]
+    fn test_camel_hand_display_round_trip() {
+        for game_type in [GameType::WithoutJoker, GameType::WithJoker] {
+            for line in EXAMPLE.lines() {
+                let hand = CamelHand::try_from((line, game_type)).expect("Should parse");
+                let printed = hand.to_string();
+
+                assert_eq!(printed, line);
+
+                let reparsed =
+                    CamelHand::try_from((printed.as_str(), game_type)).expect("Should parse");
+
+                assert_eq!(reparsed, hand);
+                assert_eq!(reparsed.bid, hand.bid);
+                assert_eq!(reparsed.strength, hand.strength);
+            }
+        }
+    }
 }
@@ -0,0 +1,38 @@
+use crate::{
+    callibration_value::CalibrationValue,
+    solver::{Solver, SolverError},
+};
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        Ok(input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(str::to_owned)
+            .collect())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, SolverError> {
+        let values = input
+            .iter()
+            .map(|line| line.parse::<CalibrationValue>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(values.iter().map(|v| v.value).sum())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, SolverError> {
+        let values = input
+            .iter()
+            .map(|line| CalibrationValue::try_new_v2(line.to_owned()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(values.iter().map(|v| v.value).sum())
+    }
+}
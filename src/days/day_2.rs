@@ -0,0 +1,39 @@
+use crate::{
+    cube_game::CubeGame,
+    solver::{Solver, SolverError},
+};
+
+const MAX_RED: usize = 12;
+const MAX_GREEN: usize = 13;
+const MAX_BLUE: usize = 14;
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    type Input = Vec<CubeGame>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        Ok(input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(str::parse::<CubeGame>)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, SolverError> {
+        Ok(input
+            .iter()
+            .enumerate()
+            .filter(|(_i, g)| {
+                g.max_red <= MAX_RED && g.max_blue <= MAX_BLUE && g.max_green <= MAX_GREEN
+            })
+            .map(|(i, _)| i + 1)
+            .sum())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, SolverError> {
+        Ok(input.iter().map(|g| g.power()).sum())
+    }
+}
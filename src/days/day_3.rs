@@ -0,0 +1,24 @@
+use crate::{
+    engine_schematic::Schematic,
+    solver::{Solver, SolverError},
+};
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Input = Schematic;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, SolverError> {
+        Ok(input.part_numbers().map(|number| number.value).sum())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, SolverError> {
+        Ok(input.gears().sum())
+    }
+}
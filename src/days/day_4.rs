@@ -0,0 +1,39 @@
+use crate::{
+    scratch_card::ScratchCard,
+    solver::{Solver, SolverError},
+};
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Input = Vec<ScratchCard>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        Ok(input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(str::parse::<ScratchCard>)
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, SolverError> {
+        Ok(input.iter().map(|card| card.value()).sum())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, SolverError> {
+        let mut count_cards: Vec<usize> = vec![1; input.len()];
+
+        for (i, card) in input.iter().enumerate() {
+            let times = count_cards[i];
+            let last = (i + card.wins()).min(input.len() - 1);
+
+            for count in count_cards[i + 1..=last].iter_mut() {
+                *count += times;
+            }
+        }
+
+        Ok(count_cards.iter().sum())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    farm_map::Almanac,
+    solver::{Solver, SolverError},
+};
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Input = Almanac;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, SolverError> {
+        let seed_to_location = input.composed();
+
+        input
+            .seeds()
+            .iter()
+            .map(|seed| seed_to_location.correspond(*seed))
+            .min()
+            .ok_or(SolverError::InvalidInputError)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, SolverError> {
+        let seed_to_location = input.composed();
+
+        let mut lowest: Option<usize> = None;
+
+        for range in input.seed_ranges()? {
+            let location = seed_to_location.lowest_in_range(&range);
+
+            lowest = lowest.into_iter().chain(location).min();
+        }
+
+        lowest.ok_or(SolverError::InvalidInputError)
+    }
+}
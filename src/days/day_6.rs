@@ -0,0 +1,24 @@
+use crate::{
+    boat_race::RaceSheet,
+    solver::{Solver, SolverError},
+};
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Input = RaceSheet;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        Ok(input.parse()?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, SolverError> {
+        Ok(input.margin_of_error())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, SolverError> {
+        Ok(input.kerned()?.ways_to_win())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{
+    camel_hand::{CamelTable, GameType},
+    solver::{Solver, SolverError},
+};
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    /// The hands ranked without and with jokers.
+    type Input = (CamelTable, CamelTable);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        Ok((
+            CamelTable::try_from((input, GameType::WithoutJoker))?,
+            CamelTable::try_from((input, GameType::WithJoker))?,
+        ))
+    }
+
+    fn part_one((table, _): &Self::Input) -> Result<Self::PartOne, SolverError> {
+        Ok(table.total_winnings())
+    }
+
+    fn part_two((_, table): &Self::Input) -> Result<Self::PartTwo, SolverError> {
+        Ok(table.total_winnings())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    network::Network,
+    solver::{Solver, SolverError},
+};
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    type Input = Network;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError> {
+        Ok(input.parse::<Network>()?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, SolverError> {
+        Ok(input.walk()?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, SolverError> {
+        Ok(input.ghost_walk(|id| id.ends_with('A'), |id| id.ends_with('Z'))?)
+    }
+}
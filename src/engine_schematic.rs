@@ -0,0 +1,200 @@
+use std::{collections::HashMap, num::ParseIntError, str::FromStr};
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SchematicParseError {
+    #[error("Invalid input error")]
+    InvalidInputError,
+    #[error("Can't parse number error")]
+    ParseIntError(#[from] ParseIntError),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PartNumber {
+    pub row: usize,
+    pub column: usize,
+    pub len: usize,
+    pub value: usize,
+}
+
+impl PartNumber {
+    fn new(row: usize, column: usize, len: usize, value: usize) -> Self {
+        Self {
+            row,
+            column,
+            len,
+            value,
+        }
+    }
+
+    pub fn is_adjacent(&self, row: usize, column: usize) -> bool {
+        row + 1 >= self.row
+            && row <= self.row + 1
+            && column + 1 >= self.column
+            && column <= self.column + self.len
+    }
+
+    fn neighbors(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let rows = self.row.saturating_sub(1)..=self.row + 1;
+
+        rows.flat_map(move |r| {
+            (self.column.saturating_sub(1)..=self.column + self.len).map(move |c| (r, c))
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    numbers: Vec<PartNumber>,
+    symbols: HashMap<(usize, usize), char>,
+}
+
+impl Schematic {
+    fn new(numbers: Vec<PartNumber>, symbols: HashMap<(usize, usize), char>) -> Self {
+        Self { numbers, symbols }
+    }
+
+    /// Numbers adjacent to at least one symbol, diagonals included.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &PartNumber> + '_ {
+        self.numbers.iter().filter(|number| {
+            number
+                .neighbors()
+                .any(|position| self.symbols.contains_key(&position))
+        })
+    }
+
+    /// Gear ratios of every `*` adjacent to exactly two part numbers.
+    pub fn gears(&self) -> impl Iterator<Item = usize> + '_ {
+        self.symbols
+            .iter()
+            .filter(|(_, symbol)| **symbol == '*')
+            .filter_map(|((row, column), _)| {
+                let mut adjacent = self
+                    .numbers
+                    .iter()
+                    .filter(|number| number.is_adjacent(*row, *column));
+
+                match (adjacent.next(), adjacent.next(), adjacent.next()) {
+                    (Some(first), Some(second), None) => Some(first.value * second.value),
+                    _ => None,
+                }
+            })
+    }
+}
+
+impl FromStr for Schematic {
+    type Err = SchematicParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers: Vec<PartNumber> = vec![];
+        let mut symbols: HashMap<(usize, usize), char> = HashMap::new();
+        let mut width: Option<usize> = None;
+
+        for (row, line) in s.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            if !line.is_ascii() || width.is_some_and(|width| width != line.len()) {
+                return Err(SchematicParseError::InvalidInputError);
+            }
+            width = Some(line.len());
+
+            let mut start: Option<usize> = None;
+
+            for (column, char) in line.char_indices() {
+                if char.is_ascii_digit() {
+                    start.get_or_insert(column);
+
+                    continue;
+                }
+
+                if let Some(start) = start.take() {
+                    numbers.push(PartNumber::new(
+                        row,
+                        start,
+                        column - start,
+                        line[start..column].parse()?,
+                    ));
+                }
+
+                if char != '.' {
+                    symbols.insert((row, column), char);
+                }
+            }
+
+            if let Some(start) = start {
+                numbers.push(PartNumber::new(
+                    row,
+                    start,
+                    line.len() - start,
+                    line[start..].parse()?,
+                ));
+            }
+        }
+
+        Ok(Schematic::new(numbers, symbols))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn test_schematic_from_str() {
+        let schematic: Schematic = EXAMPLE.parse().expect("Should parse");
+
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(schematic.numbers[0], PartNumber::new(0, 0, 3, 467));
+        assert_eq!(schematic.numbers[1], PartNumber::new(0, 5, 3, 114));
+        assert_eq!(schematic.symbols.get(&(1, 3)), Some(&'*'));
+        assert_eq!(schematic.symbols.len(), 6);
+    }
+
+    #[test]
+    fn test_schematic_part_numbers() {
+        let schematic: Schematic = EXAMPLE.parse().expect("Should parse");
+
+        assert_eq!(
+            schematic.part_numbers().map(|n| n.value).sum::<usize>(),
+            4361
+        );
+        assert!(!schematic.part_numbers().any(|n| n.value == 114));
+        assert!(!schematic.part_numbers().any(|n| n.value == 58));
+    }
+
+    #[test]
+    fn test_schematic_gears() {
+        let schematic: Schematic = EXAMPLE.parse().expect("Should parse");
+
+        let mut gears = schematic.gears().collect::<Vec<_>>();
+        gears.sort();
+
+        assert_eq!(gears, vec![16345, 451490]);
+    }
+
+    #[test]
+    fn test_schematic_number_at_line_end() {
+        let schematic: Schematic = "..12\n...#".parse().expect("Should parse");
+
+        assert_eq!(schematic.part_numbers().map(|n| n.value).sum::<usize>(), 12);
+    }
+
+    #[test]
+    fn test_schematic_uneven_rows() {
+        assert!("...\n....".parse::<Schematic>().is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use std::{num::ParseIntError, str::FromStr};
+use std::{fmt::Display, num::ParseIntError, str::FromStr};
 
 use thiserror::Error;
 
@@ -14,94 +14,190 @@ impl FarmMap {
         Self { lines }
     }
 
-    pub fn correspond(&self, value: usize) -> Result<usize, FarmMapParseError> {
-        let _result: Option<usize> = None;
+    /// Line covering `value`, found by binary search over the lines sorted by source.
+    fn line_for(&self, value: usize) -> Option<&FarmMapLine> {
+        let index = self
+            .lines
+            .partition_point(|line| line.source_range <= value);
 
-        let mut lines_iter = self.lines.iter();
+        index
+            .checked_sub(1)
+            .map(|i| &self.lines[i])
+            .filter(|line| value < line.source_end())
+    }
 
-        let mut next_line = lines_iter.next();
+    pub fn correspond(&self, value: usize) -> usize {
+        self.line_for(value).map_or(value, |line| line.map(value))
+    }
 
-        loop {
-            if let Some(line) = next_line {
-                if value >= line.source_range + line.range_length {
-                    next_line = lines_iter.next();
+    /// Maps a sorted slice of values in a single pass over the lines.
+    pub fn correspond_sorted(&self, values: &[usize]) -> Vec<usize> {
+        debug_assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
 
-                    continue;
-                }
+        let mut lines = self.lines.iter().peekable();
+
+        values
+            .iter()
+            .map(|value| {
+                while lines.next_if(|line| line.source_end() <= *value).is_some() {}
 
-                if value < line.source_range {
-                    return Ok(value);
+                match lines.peek() {
+                    Some(line) if line.source_range <= *value => line.map(*value),
+                    _ => *value,
                 }
+            })
+            .collect()
+    }
+
+    /// Splits the half-open range `[start, end)` at the line boundaries, pairing every piece
+    /// with the line mapping it. Pieces without a line correspond to themselves.
+    fn segments(&self, (start, end): (usize, usize)) -> Vec<(usize, usize, Option<&FarmMapLine>)> {
+        let mut segments: Vec<(usize, usize, Option<&FarmMapLine>)> = vec![];
+        let mut cursor = start;
+        let first = self
+            .lines
+            .partition_point(|line| line.source_end() <= start);
+
+        for line in self.lines[first..].iter() {
+            if cursor >= end || line.source_range >= end {
+                break;
+            }
 
-                return Ok(value + line.destination_range - line.source_range);
-            } else {
-                return Ok(value);
+            if line.source_end() <= cursor {
+                continue;
             }
+
+            if cursor < line.source_range {
+                segments.push((cursor, line.source_range, None));
+                cursor = line.source_range;
+            }
+
+            let segment_end = line.source_end().min(end);
+            segments.push((cursor, segment_end, Some(line)));
+            cursor = segment_end;
+        }
+
+        if cursor < end {
+            segments.push((cursor, end, None));
         }
+
+        segments
     }
 
-    pub fn correspond_range(
-        &self,
-        range: &(usize, usize),
-    ) -> Result<Vec<(usize, usize)>, FarmMapParseError> {
-        let _result: Option<usize> = None;
+    /// Maps the half-open range `[start, end)`, returning the mapped pieces in source order.
+    /// Together the pieces cover exactly as many values as the input range.
+    pub fn correspond_range(&self, range: &(usize, usize)) -> Vec<(usize, usize)> {
+        self.segments(*range)
+            .into_iter()
+            .map(|(start, end, line)| match line {
+                Some(line) => (line.map(start), line.map(start) + (end - start)),
+                None => (start, end),
+            })
+            .collect()
+    }
 
-        let mut lines_iter = self.lines.iter();
+    /// Lowest value any of `[start, end)` corresponds to. Every piece of the range is shifted
+    /// by a constant offset, so only the start of each piece needs to be looked at.
+    pub fn lowest_in_range(&self, range: &(usize, usize)) -> Option<usize> {
+        self.segments(*range)
+            .into_iter()
+            .map(|(start, _, line)| line.map_or(start, |line| line.map(start)))
+            .min()
+    }
 
-        let _next_line = lines_iter.next();
+    /// Every source value corresponding to `value`: one for each line whose destination
+    /// covers it, plus `value` itself when no line claims it as a source.
+    pub fn inverse(&self, value: usize) -> Vec<usize> {
+        let mut sources: Vec<usize> = self
+            .lines
+            .iter()
+            .filter(|line| {
+                line.destination_range <= value
+                    && value - line.destination_range < line.range_length
+            })
+            .map(|line| value - line.destination_range + line.source_range)
+            .collect();
+
+        if self.line_for(value).is_none() {
+            sources.push(value);
+        }
 
-        let mut start = range.0;
-        let end = range.1;
+        sources.sort();
 
-        let mut ranges: Vec<(usize, usize)> = vec![];
+        sources
+    }
 
-        for line in self.lines.iter() {
-            println!("range: {}, {}; line: {:?}", start, end, line);
-            if end < line.source_range {
-                println!("Ends before line");
+    /// Source ranges corresponding to any value of `[start, end)`, sorted and merged.
+    pub fn inverse_range(&self, &(start, end): &(usize, usize)) -> Vec<(usize, usize)> {
+        let mut ranges: Vec<(usize, usize)> = self
+            .lines
+            .iter()
+            .filter_map(|line| {
+                let from = start.max(line.destination_range);
+                let to = end.min(line.destination_range + line.range_length);
+
+                (from < to).then(|| {
+                    let source = from - line.destination_range + line.source_range;
+
+                    (source, source + (to - from))
+                })
+            })
+            .collect();
+
+        ranges.extend(
+            self.segments((start, end))
+                .into_iter()
+                .filter(|(_, _, line)| line.is_none())
+                .map(|(start, end, _)| (start, end)),
+        );
 
-                continue;
-            }
-            let line_end = line.source_range + line.range_length;
-            if start >= line_end {
-                println!("Starts after line");
+        ranges.sort();
 
-                continue;
+        let mut merged: Vec<(usize, usize)> = vec![];
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if last.1 >= start => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
             }
+        }
 
-            if start < line.source_range {
-                println!("Starts before line");
-                ranges.push((start, line.source_range));
-                start = line.source_range;
-            }
+        merged
+    }
 
-            let difference = start + line.destination_range - line.source_range;
-            println!(
-                "difference: {}, {}, {}",
-                difference, line.destination_range, line.source_range
-            );
-
-            if line_end < end {
-                println!("Line ends before end");
-                ranges.push((start + difference, line_end + difference));
-                start = line_end;
-            } else {
-                println!("Ends after line");
-                ranges.push((line_end, end));
-            }
+    /// Single map equivalent to applying `self` and then `next`, with the breakpoints of both
+    /// merged into one set of lines.
+    pub fn compose(&self, next: &FarmMap) -> FarmMap {
+        let mut lines: Vec<FarmMapLine> = vec![];
 
-            println!("result: {:?}", ranges);
-        }
+        for (start, end, line) in self.segments((0, usize::MAX)) {
+            let mapped_start = line.map_or(start, |line| line.map(start));
+            let mapped_end = mapped_start + (end - start);
+
+            for (next_start, next_end, next_line) in next.segments((mapped_start, mapped_end)) {
+                let source = start + (next_start - mapped_start);
+                let destination = next_line.map_or(next_start, |line| line.map(next_start));
+
+                if source == destination {
+                    continue;
+                }
 
-        if ranges.is_empty() {
-            return Ok(vec![*range]);
+                match lines.last_mut() {
+                    Some(last)
+                        if last.source_end() == source
+                            && last.destination_range + last.range_length == destination =>
+                    {
+                        last.range_length += next_end - next_start;
+                    }
+                    _ => lines.push(FarmMapLine::new(destination, source, next_end - next_start)),
+                }
+            }
         }
 
-        Ok(ranges)
+        FarmMap::new(lines)
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FarmMapLine {
     destination_range: usize,
     source_range: usize,
@@ -116,11 +212,52 @@ impl FarmMapLine {
             range_length: range_lengh,
         }
     }
+
+    fn source_end(&self) -> usize {
+        self.source_range + self.range_length
+    }
+
+    fn map(&self, value: usize) -> usize {
+        value - self.source_range + self.destination_range
+    }
+
+    fn validate(&self) -> Result<(), FarmMapParseError> {
+        if self.range_length == 0 {
+            return Err(FarmMapParseError::EmptyRange(self.clone()));
+        }
+
+        if self.source_range.checked_add(self.range_length).is_none()
+            || self
+                .destination_range
+                .checked_add(self.range_length)
+                .is_none()
+        {
+            return Err(FarmMapParseError::Overflow(self.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for FarmMapLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.destination_range, self.source_range, self.range_length
+        )
+    }
+}
+
+impl Ord for FarmMapLine {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.source_range.cmp(&other.source_range)
+    }
 }
 
 impl PartialOrd for FarmMapLine {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.source_range.partial_cmp(&other.source_range)
+        Some(self.cmp(other))
     }
 }
 
@@ -130,21 +267,52 @@ pub enum FarmMapParseError {
     InvalidInputError,
     #[error("Can't parse number error")]
     ParseIntError(#[from] ParseIntError),
+    #[error("Expected 3 numbers, found {0}")]
+    WrongArity(usize),
+    #[error("Range `{0}` overflows")]
+    Overflow(FarmMapLine),
+    #[error("Range `{0}` is empty")]
+    EmptyRange(FarmMapLine),
+    #[error("Source ranges of `{first}` and `{second}` overlap")]
+    OverlappingSourceRanges {
+        first: FarmMapLine,
+        second: FarmMapLine,
+    },
 }
 
 impl TryFrom<Vec<&str>> for FarmMap {
     type Error = FarmMapParseError;
 
     fn try_from(value: Vec<&str>) -> Result<Self, Self::Error> {
-        let mut lines: Vec<FarmMapLine> = vec![];
-        for s in value.iter() {
-            let _ranges = split_whitespace_and_parse_to_usize(s)?;
+        let lines = value
+            .iter()
+            .map(|s| s.parse())
+            .collect::<Result<Vec<FarmMapLine>, _>>()?;
+
+        FarmMap::try_from(lines)
+    }
+}
+
+impl TryFrom<Vec<FarmMapLine>> for FarmMap {
+    type Error = FarmMapParseError;
 
-            lines.push(s.parse()?);
+    fn try_from(mut lines: Vec<FarmMapLine>) -> Result<Self, Self::Error> {
+        for line in lines.iter() {
+            line.validate()?;
         }
 
         lines.sort();
 
+        if let Some(pair) = lines
+            .windows(2)
+            .find(|pair| pair[0].source_end() > pair[1].source_range)
+        {
+            return Err(FarmMapParseError::OverlappingSourceRanges {
+                first: pair[0].clone(),
+                second: pair[1].clone(),
+            });
+        }
+
         Ok(FarmMap::new(lines))
     }
 }
@@ -155,7 +323,254 @@ impl FromStr for FarmMapLine {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let ranges = split_whitespace_and_parse_to_usize(s)?;
 
-        Ok(FarmMapLine::new(ranges[0], ranges[1], ranges[2]))
+        let [destination_range, source_range, range_length] = ranges[..] else {
+            return Err(FarmMapParseError::WrongArity(ranges.len()));
+        };
+
+        let line = FarmMapLine::new(destination_range, source_range, range_length);
+        line.validate()?;
+
+        Ok(line)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum AlmanacParseError {
+    #[error("Missing seeds line")]
+    MissingSeeds,
+    #[error("Can't parse seeds on line {line}")]
+    InvalidSeeds { line: usize, source: ParseIntError },
+    #[error("Invalid section header on line {line}: `{text}`")]
+    InvalidHeader { line: usize, text: String },
+    #[error("Section `{section}` on line {line} doesn't continue from `{expected}`")]
+    UnchainedSection {
+        section: String,
+        expected: String,
+        line: usize,
+    },
+    #[error("Invalid line {line} in section `{section}`")]
+    InvalidLine {
+        section: String,
+        line: usize,
+        source: FarmMapParseError,
+    },
+    #[error("Invalid section `{section}` starting on line {line}")]
+    InvalidSection {
+        section: String,
+        line: usize,
+        source: FarmMapParseError,
+    },
+    #[error("Seed `{0}` has no range length")]
+    UnpairedSeed(usize),
+    #[error("Seed range of {length} from `{start}` overflows")]
+    SeedRangeOverflow { start: usize, length: usize },
+}
+
+#[derive(Debug)]
+pub struct AlmanacSection {
+    pub source: String,
+    pub destination: String,
+    map: FarmMap,
+}
+
+impl AlmanacSection {
+    pub fn new(source: String, destination: String, map: FarmMap) -> Self {
+        Self {
+            source,
+            destination,
+            map,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        format!("{}-to-{}", self.source, self.destination)
+    }
+
+    pub fn map(&self) -> &FarmMap {
+        &self.map
+    }
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<usize>,
+    sections: Vec<AlmanacSection>,
+}
+
+impl Almanac {
+    pub fn new(seeds: Vec<usize>, sections: Vec<AlmanacSection>) -> Self {
+        Self { seeds, sections }
+    }
+
+    pub fn seeds(&self) -> &[usize] {
+        &self.seeds
+    }
+
+    /// Seeds read as `start length` pairs, each one the half-open range `[start, start + length)`.
+    pub fn seed_ranges(&self) -> Result<Vec<(usize, usize)>, AlmanacParseError> {
+        self.seeds
+            .chunks(2)
+            .map(|chunk| match chunk {
+                [start, length] => start.checked_add(*length).map(|end| (*start, end)).ok_or(
+                    AlmanacParseError::SeedRangeOverflow {
+                        start: *start,
+                        length: *length,
+                    },
+                ),
+                [start] => Err(AlmanacParseError::UnpairedSeed(*start)),
+                _ => unreachable!("Chunks hold one or two seeds"),
+            })
+            .collect()
+    }
+
+    /// Sections in document order, each one continuing from the destination of the previous.
+    pub fn sections(&self) -> &[AlmanacSection] {
+        &self.sections
+    }
+
+    pub fn maps(&self) -> impl Iterator<Item = &FarmMap> + '_ {
+        self.sections.iter().map(AlmanacSection::map)
+    }
+
+    /// Looks up a map by its `source-to-destination` name.
+    pub fn map(&self, name: &str) -> Option<&FarmMap> {
+        let (source, destination) = name.split_once("-to-")?;
+
+        self.sections
+            .iter()
+            .find(|s| s.source == source && s.destination == destination)
+            .map(AlmanacSection::map)
+    }
+
+    pub fn correspond(&self, seed: usize) -> usize {
+        self.maps().fold(seed, |value, map| map.correspond(value))
+    }
+
+    /// Seeds ending up at `location` after walking the whole chain.
+    pub fn seeds_for_location(&self, location: usize) -> Vec<usize> {
+        self.composed().inverse(location)
+    }
+
+    /// Seed ranges ending up anywhere in the location range `[start, end)`.
+    pub fn seeds_for_locations(&self, range: &(usize, usize)) -> Vec<(usize, usize)> {
+        self.composed().inverse_range(range)
+    }
+
+    /// The whole chain of maps composed into a single seed to location map.
+    pub fn composed(&self) -> FarmMap {
+        self.maps()
+            .fold(FarmMap::new(vec![]), |composed, map| composed.compose(map))
+    }
+}
+
+struct PendingSection<'a> {
+    source: &'a str,
+    destination: &'a str,
+    line: usize,
+    lines: Vec<FarmMapLine>,
+}
+
+impl<'a> PendingSection<'a> {
+    fn name(&self) -> String {
+        format!("{}-to-{}", self.source, self.destination)
+    }
+
+    fn finish(self) -> Result<AlmanacSection, AlmanacParseError> {
+        let section = self.name();
+        let map =
+            FarmMap::try_from(self.lines).map_err(|source| AlmanacParseError::InvalidSection {
+                section,
+                line: self.line,
+                source,
+            })?;
+
+        Ok(AlmanacSection::new(
+            self.source.to_owned(),
+            self.destination.to_owned(),
+            map,
+        ))
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = AlmanacParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+        let (line, seeds) = lines
+            .find(|(_, line)| !line.is_empty())
+            .and_then(|(i, line)| Some((i, line.strip_prefix("seeds:")?)))
+            .ok_or(AlmanacParseError::MissingSeeds)?;
+        let seeds = split_whitespace_and_parse_to_usize(seeds)
+            .map_err(|source| AlmanacParseError::InvalidSeeds { line, source })?;
+
+        let mut sections: Vec<AlmanacSection> = vec![];
+        let mut pending: Option<PendingSection> = None;
+
+        for (line, text) in lines {
+            if text.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = text.strip_suffix(" map:") {
+                let (source, destination) =
+                    name.split_once("-to-")
+                        .ok_or_else(|| AlmanacParseError::InvalidHeader {
+                            line,
+                            text: text.to_owned(),
+                        })?;
+
+                let expected = match &pending {
+                    Some(previous) => previous.destination,
+                    None => "seed",
+                };
+
+                if source != expected {
+                    return Err(AlmanacParseError::UnchainedSection {
+                        section: name.to_owned(),
+                        expected: expected.to_owned(),
+                        line,
+                    });
+                }
+
+                if let Some(previous) = pending.take() {
+                    sections.push(previous.finish()?);
+                }
+
+                pending = Some(PendingSection {
+                    source,
+                    destination,
+                    line,
+                    lines: vec![],
+                });
+
+                continue;
+            }
+
+            let section = pending
+                .as_mut()
+                .ok_or_else(|| AlmanacParseError::InvalidHeader {
+                    line,
+                    text: text.to_owned(),
+                })?;
+
+            let farm_map_line = text
+                .parse()
+                .map_err(|source| AlmanacParseError::InvalidLine {
+                    section: section.name(),
+                    line,
+                    source,
+                })?;
+
+            section.lines.push(farm_map_line);
+        }
+
+        if let Some(previous) = pending {
+            sections.push(previous.finish()?);
+        }
+
+        Ok(Almanac::new(seeds, sections))
     }
 }
 
@@ -179,10 +594,10 @@ mod tests {
             "50 98 2".parse().expect("input to be parsed")
         );
 
-        assert_eq!(farm_map.correspond(13).expect("Should return value"), 13);
-        assert_eq!(farm_map.correspond(14).expect("Should return value"), 14);
-        assert_eq!(farm_map.correspond(55).expect("Should return value"), 57);
-        assert_eq!(farm_map.correspond(79).expect("Should return value"), 81);
+        assert_eq!(farm_map.correspond(13), 13);
+        assert_eq!(farm_map.correspond(14), 14);
+        assert_eq!(farm_map.correspond(55), 57);
+        assert_eq!(farm_map.correspond(79), 81);
     }
 
     #[test]
@@ -192,10 +607,10 @@ mod tests {
             .expect("Input to be parsed");
 
         assert_eq!(farm_map.lines.len(), 3);
-        assert_eq!(farm_map.correspond(81).expect("Should return value"), 81);
-        assert_eq!(farm_map.correspond(14).expect("Should return value"), 53);
-        assert_eq!(farm_map.correspond(57).expect("Should return value"), 57);
-        assert_eq!(farm_map.correspond(13).expect("Should return value"), 52);
+        assert_eq!(farm_map.correspond(81), 81);
+        assert_eq!(farm_map.correspond(14), 53);
+        assert_eq!(farm_map.correspond(57), 57);
+        assert_eq!(farm_map.correspond(13), 52);
     }
 
     #[test]
@@ -205,19 +620,377 @@ mod tests {
             .expect("Input to be parsed");
 
         assert_eq!(farm_map.lines.len(), 4);
-        assert_eq!(farm_map.correspond(81).expect("Should return value"), 81);
-        assert_eq!(farm_map.correspond(53).expect("Should return value"), 49);
-        assert_eq!(farm_map.correspond(57).expect("Should return value"), 53);
-        assert_eq!(farm_map.correspond(52).expect("Should return value"), 41);
+        assert_eq!(farm_map.correspond(81), 81);
+        assert_eq!(farm_map.correspond(53), 49);
+        assert_eq!(farm_map.correspond(57), 53);
+        assert_eq!(farm_map.correspond(52), 41);
     }
 
-    // #[test]
+    #[test]
+    fn test_farm_map_empty_range() {
+        for lines in [vec!["0 10 5", "100 10 0"], vec!["100 10 0", "0 10 5"]] {
+            let farm_map: Result<FarmMap, _> = lines.try_into();
+
+            assert!(matches!(
+                farm_map,
+                Err(FarmMapParseError::EmptyRange(line)) if line == FarmMapLine::new(100, 10, 0)
+            ));
+        }
+
+        assert!(matches!(
+            "1 2 0".parse::<FarmMapLine>(),
+            Err(FarmMapParseError::EmptyRange(_))
+        ));
+    }
+
+    #[test]
     fn test_farm_map_try_from_4() {
         let farm_map: FarmMap = vec!["49 53 8", "0 11 42", "42 0 7", "57 7 4"]
             .try_into()
             .expect("Input to be parsed");
 
         assert_eq!(farm_map.lines.len(), 4);
-        assert_eq!(farm_map.correspond_range(&(0, 50)).expect("Should return value"), vec![(81, 88)]);
+        assert_eq!(
+            farm_map.correspond_range(&(0, 50)),
+            vec![(42, 49), (57, 61), (0, 39)]
+        );
+    }
+
+    #[test]
+    fn test_farm_map_correspond_range_gaps() {
+        let farm_map: FarmMap = vec!["50 98 2", "52 50 48"]
+            .try_into()
+            .expect("Input to be parsed");
+
+        assert_eq!(
+            farm_map.correspond_range(&(40, 110)),
+            vec![(40, 50), (52, 100), (50, 52), (100, 110)]
+        );
+        assert_eq!(farm_map.correspond_range(&(60, 61)), vec![(62, 63)]);
+        assert_eq!(farm_map.correspond_range(&(0, 10)), vec![(0, 10)]);
+        assert!(farm_map.correspond_range(&(10, 10)).is_empty());
+    }
+
+    #[test]
+    fn test_farm_map_correspond_range_matches_correspond() {
+        let farm_map: FarmMap = vec!["49 53 8", "0 11 42", "42 0 7", "57 7 4"]
+            .try_into()
+            .expect("Input to be parsed");
+
+        for start in 0..70 {
+            for end in start..70 {
+                let mapped = farm_map
+                    .correspond_range(&(start, end))
+                    .into_iter()
+                    .flat_map(|(start, end)| start..end)
+                    .collect::<Vec<_>>();
+                let expected = (start..end)
+                    .map(|value| farm_map.correspond(value))
+                    .collect::<Vec<_>>();
+
+                assert_eq!(mapped, expected);
+            }
+        }
+    }
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn test_almanac_from_str() {
+        let almanac: Almanac = EXAMPLE.parse().expect("Should parse");
+
+        assert_eq!(almanac.seeds(), &[79, 14, 55, 13]);
+        assert_eq!(almanac.sections().len(), 7);
+        assert_eq!(almanac.sections()[0].name(), "seed-to-soil");
+        assert_eq!(almanac.sections()[6].destination, "location");
+
+        let fertilizer_to_water = almanac
+            .map("fertilizer-to-water")
+            .expect("Map should exist");
+        assert_eq!(fertilizer_to_water.lines.len(), 4);
+        assert!(almanac.map("seed-to-water").is_none());
+
+        let locations = almanac
+            .seeds()
+            .iter()
+            .map(|seed| almanac.correspond(*seed))
+            .collect::<Vec<_>>();
+        assert_eq!(locations, vec![82, 43, 86, 35]);
+    }
+
+    #[test]
+    fn test_almanac_seed_ranges() {
+        let almanac: Almanac = EXAMPLE.parse().expect("Should parse");
+
+        assert_eq!(
+            almanac.seed_ranges().expect("Should pair"),
+            vec![(79, 93), (55, 68)]
+        );
+
+        let almanac = Almanac::new(vec![usize::MAX - 1, 5], vec![]);
+        assert!(matches!(
+            almanac.seed_ranges(),
+            Err(AlmanacParseError::SeedRangeOverflow { start, length: 5 }) if start == usize::MAX - 1
+        ));
+
+        let almanac = Almanac::new(vec![1, 2, 3], vec![]);
+        assert!(matches!(
+            almanac.seed_ranges(),
+            Err(AlmanacParseError::UnpairedSeed(3))
+        ));
+    }
+
+    #[test]
+    fn test_almanac_errors() {
+        assert!(matches!(
+            "".parse::<Almanac>(),
+            Err(AlmanacParseError::MissingSeeds)
+        ));
+        assert!(matches!(
+            "seeds: 1 x".parse::<Almanac>(),
+            Err(AlmanacParseError::InvalidSeeds { line: 1, .. })
+        ));
+        assert!(matches!(
+            "seeds: 1\n\n1 2 3".parse::<Almanac>(),
+            Err(AlmanacParseError::InvalidHeader { line: 3, .. })
+        ));
+        assert!(matches!(
+            "seeds: 1\n\nseed-to-soil map:\n1 2 3\nfertilizer-to-water map:".parse::<Almanac>(),
+            Err(AlmanacParseError::UnchainedSection { line: 5, .. })
+        ));
+
+        let error = "seeds: 1\n\nseed-to-soil map:\n1 2 3\n4 x 6"
+            .parse::<Almanac>()
+            .expect_err("Should fail");
+        assert!(
+            matches!(&error, AlmanacParseError::InvalidLine { section, line: 5, .. } if section == "seed-to-soil")
+        );
+        assert_eq!(
+            error.to_string(),
+            "Invalid line 5 in section `seed-to-soil`"
+        );
+    }
+
+    #[test]
+    fn test_farm_map_compose() {
+        let first: FarmMap = vec!["50 98 2", "52 50 48"]
+            .try_into()
+            .expect("Input to be parsed");
+        let second: FarmMap = vec!["0 15 37", "37 52 2", "39 0 15"]
+            .try_into()
+            .expect("Input to be parsed");
+
+        let composed = first.compose(&second);
+
+        for value in 0..200 {
+            let expected = second.correspond(first.correspond(value));
+
+            assert_eq!(composed.correspond(value), expected);
+        }
+    }
+
+    #[test]
+    fn test_farm_map_compose_merges_lines() {
+        let first: FarmMap = vec!["10 0 5"].try_into().expect("Input to be parsed");
+        let second: FarmMap = vec!["20 10 2", "22 12 3"]
+            .try_into()
+            .expect("Input to be parsed");
+
+        // 10..15 passes the first map unchanged, so it lands on 20..25 as well.
+        assert_eq!(
+            first.compose(&second).lines,
+            vec![FarmMapLine::new(20, 0, 5), FarmMapLine::new(20, 10, 5)]
+        );
+
+        let swap: FarmMap = vec!["0 10 5", "10 0 5"]
+            .try_into()
+            .expect("Input to be parsed");
+
+        assert!(swap.compose(&swap).lines.is_empty());
+    }
+
+    #[test]
+    fn test_almanac_composed() {
+        let almanac: Almanac = EXAMPLE.parse().expect("Should parse");
+        let composed = almanac.composed();
+
+        for seed in 0..150 {
+            assert_eq!(composed.correspond(seed), almanac.correspond(seed));
+        }
+
+        let lowest = almanac
+            .seeds()
+            .chunks(2)
+            .filter_map(|chunk| composed.lowest_in_range(&(chunk[0], chunk[0] + chunk[1])))
+            .min();
+
+        assert_eq!(lowest, Some(46));
+    }
+
+    #[test]
+    fn test_farm_map_inverse() {
+        let farm_map: FarmMap = vec!["50 98 2", "52 50 48"]
+            .try_into()
+            .expect("Input to be parsed");
+
+        assert_eq!(farm_map.inverse(81), vec![79]);
+        assert_eq!(farm_map.inverse(13), vec![13]);
+        // 50 is produced by 98, while 50 itself is remapped to 52.
+        assert_eq!(farm_map.inverse(50), vec![98]);
+        // 99 is claimed as a source, but 97 lands on it, and 52..100 are shifted by two.
+        assert_eq!(farm_map.inverse(99), vec![97]);
+        assert_eq!(farm_map.inverse(100), vec![100]);
+        // 51 is itself remapped, so only 99 produces it.
+        assert_eq!(farm_map.inverse(51), vec![99]);
+
+        let overlapping: FarmMap = vec!["10 0 5", "10 20 5"]
+            .try_into()
+            .expect("Input to be parsed");
+
+        assert_eq!(overlapping.inverse(12), vec![2, 12, 22]);
+        assert_eq!(overlapping.inverse(usize::MAX), vec![usize::MAX]);
+
+        let top: FarmMap = vec![format!("{} 0 1", usize::MAX - 1).as_str()]
+            .try_into()
+            .expect("Input to be parsed");
+
+        assert_eq!(top.inverse(usize::MAX - 1), vec![0, usize::MAX - 1]);
+        assert_eq!(top.inverse(usize::MAX), vec![usize::MAX]);
+        assert_eq!(overlapping.inverse(22), vec![]);
+        assert_eq!(
+            overlapping.inverse_range(&(8, 13)),
+            vec![(0, 3), (8, 13), (20, 23)]
+        );
+    }
+
+    #[test]
+    fn test_farm_map_inverse_matches_correspond() {
+        let farm_map: FarmMap = vec!["49 53 8", "0 11 42", "42 0 7", "57 7 4", "60 70 5"]
+            .try_into()
+            .expect("Input to be parsed");
+
+        for value in 0..100 {
+            let expected = (0..200)
+                .filter(|source| farm_map.correspond(*source) == value)
+                .collect::<Vec<_>>();
+
+            assert_eq!(farm_map.inverse(value), expected, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_almanac_seeds_for_location() {
+        let almanac: Almanac = EXAMPLE.parse().expect("Should parse");
+
+        assert_eq!(almanac.seeds_for_location(82), vec![79]);
+        assert_eq!(almanac.seeds_for_location(46), vec![82]);
+
+        for (start, end) in almanac.seeds_for_locations(&(0, 46)) {
+            for seed in start..end {
+                assert!(almanac.correspond(seed) < 46);
+            }
+        }
+    }
+
+    #[test]
+    fn test_farm_map_correspond_sorted() {
+        let farm_map: FarmMap = vec!["49 53 8", "0 11 42", "42 0 7", "57 7 4"]
+            .try_into()
+            .expect("Input to be parsed");
+
+        let values = (0..100).collect::<Vec<_>>();
+        let expected = values
+            .iter()
+            .map(|value| farm_map.correspond(*value))
+            .collect::<Vec<_>>();
+
+        assert_eq!(farm_map.correspond_sorted(&values), expected);
+        assert_eq!(
+            farm_map.correspond_sorted(&[5, 5, 60, 99]),
+            vec![47, 47, 56, 99]
+        );
+        assert!(farm_map.correspond_sorted(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_farm_map_line_validation() {
+        assert!(matches!(
+            "1 2".parse::<FarmMapLine>(),
+            Err(FarmMapParseError::WrongArity(2))
+        ));
+        assert!(matches!(
+            "1 2 3 4".parse::<FarmMapLine>(),
+            Err(FarmMapParseError::WrongArity(4))
+        ));
+        assert!(matches!(
+            format!("0 {} 2", usize::MAX).parse::<FarmMapLine>(),
+            Err(FarmMapParseError::Overflow(_))
+        ));
+        assert!(matches!(
+            format!("{} 0 2", usize::MAX).parse::<FarmMapLine>(),
+            Err(FarmMapParseError::Overflow(_))
+        ));
+        assert!(format!("0 {} 1", usize::MAX - 1)
+            .parse::<FarmMapLine>()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_farm_map_overlapping_source_ranges() {
+        let error = FarmMap::try_from(vec!["50 98 2", "52 50 48", "0 90 5"])
+            .expect_err("Should not be parsed");
+
+        assert_eq!(
+            error.to_string(),
+            "Source ranges of `52 50 48` and `0 90 5` overlap"
+        );
+
+        // Touching ranges are fine.
+        assert!(FarmMap::try_from(vec!["0 10 5", "20 15 5"]).is_ok());
+
+        let error = "seeds: 1\n\nseed-to-soil map:\n1 2 3\n4 3 6"
+            .parse::<Almanac>()
+            .expect_err("Should fail");
+        assert!(matches!(
+            error,
+            AlmanacParseError::InvalidSection {
+                line: 3,
+                source: FarmMapParseError::OverlappingSourceRanges { .. },
+                ..
+            }
+        ));
     }
 }
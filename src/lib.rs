@@ -1,10 +1,14 @@
 pub mod callibration_value;
 pub mod cube_game;
+pub mod engine_schematic;
 pub mod scratch_card;
 pub mod farm_map;
 pub mod utils;
 pub mod camel_hand;
 pub mod network;
+pub mod boat_race;
+pub mod solver;
+pub mod days;
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
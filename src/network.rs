@@ -1,5 +1,7 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{self, Write},
+    io::{self, BufRead},
     num::{ParseIntError, TryFromIntError},
     str::FromStr,
 };
@@ -14,14 +16,53 @@ pub enum NetworkParseError {
     ParseIntError(#[from] ParseIntError),
     #[error("Can't parse number error")]
     TryFromIntError(#[from] TryFromIntError),
+    #[error("Can't read input")]
+    Io(#[from] io::Error),
+    #[error("Can't write output")]
+    Format(#[from] fmt::Error),
+    #[error("Node `{0}` doesn't exist")]
+    MissingNode(String),
+    #[error("Goal is unreachable")]
+    GoalUnreachable,
+    #[error("Combining the walks needs more than {0} candidate steps")]
+    TooManyCandidates(usize),
+    #[error("Combined cycle length overflows")]
+    CycleOverflow,
+    #[error("Missing directions on line {line}")]
+    EmptyDirections { line: usize },
+    #[error("Invalid directions on line {line}: `{text}`")]
+    InvalidDirections { line: usize, text: String },
+    #[error("Invalid line {line}: `{text}`")]
+    InvalidLine { line: usize, text: String },
+    #[error("Node `{id}` on line {line} is already defined: `{text}`")]
+    DuplicateNode {
+        line: usize,
+        text: String,
+        id: String,
+    },
+    #[error("Node `{id}` referenced on line {line} doesn't exist: `{text}`")]
+    DanglingReference {
+        line: usize,
+        text: String,
+        id: String,
+    },
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
     Left,
     Right,
 }
 
+impl Direction {
+    fn side(&self) -> usize {
+        match self {
+            Direction::Left => 0,
+            Direction::Right => 1,
+        }
+    }
+}
+
 impl TryFrom<char> for Direction {
     type Error = NetworkParseError;
 
@@ -47,71 +88,704 @@ impl Node {
     }
 }
 
+/// The node in puzzle notation, like `AAA = (BBB, CCC)`.
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = ({}, {})", self.index, self.left, self.right)
+    }
+}
+
 impl FromStr for Node {
     type Err = NetworkParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (id, node) = s.split_once(" = ").expect("Should split");
-        let s = &node[1..node.len() - 1];
-        let (left, right) = s
-            .split_once(", ")
+        let (id, node) = s
+            .split_once(" = ")
+            .ok_or(NetworkParseError::InvalidInputError)?;
+        let (left, right) = node
+            .strip_prefix('(')
+            .and_then(|node| node.strip_suffix(')'))
+            .and_then(|node| node.split_once(", "))
             .ok_or(NetworkParseError::InvalidInputError)?;
 
+        if [id, left, right].iter().any(|id| id.is_empty()) {
+            return Err(NetworkParseError::InvalidInputError);
+        }
+
         Ok(Node::new(id.to_owned(), left.to_owned(), right.to_owned()))
     }
 }
 
 pub struct Network {
     directions: Vec<Direction>,
-    nodes: Vec<Node>,
-    node_index: HashMap<String, usize>,
+    ids: Vec<String>,
+    /// Left and right neighbours of every node, as indices into `ids`.
+    links: Vec<[u32; 2]>,
+    node_index: HashMap<String, u32>,
 }
 
 impl Network {
-    pub fn new(
-        directions: Vec<Direction>,
-        nodes: Vec<Node>,
-        node_index: HashMap<String, usize>,
-    ) -> Self {
-        Self {
+    /// Resolves every node id into a dense index, so walks only index into `links`.
+    pub fn new(directions: Vec<Direction>, nodes: Vec<Node>) -> Result<Self, NetworkParseError> {
+        let node_index = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| Ok((node.index.to_owned(), u32::try_from(i)?)))
+            .collect::<Result<HashMap<_, _>, NetworkParseError>>()?;
+
+        let resolve = |id: &str| {
+            node_index
+                .get(id)
+                .copied()
+                .ok_or_else(|| NetworkParseError::MissingNode(id.to_owned()))
+        };
+
+        let links = nodes
+            .iter()
+            .map(|node| Ok([resolve(&node.left)?, resolve(&node.right)?]))
+            .collect::<Result<Vec<_>, NetworkParseError>>()?;
+
+        let ids = nodes.into_iter().map(|node| node.index).collect();
+
+        Ok(Self {
             directions,
-            nodes,
+            ids,
+            links,
             node_index,
-        }
+        })
     }
 
+    /// Steps from `AAA` to `ZZZ`. Fails once the walk must have repeated a (node, direction
+    /// index) state without having reached `ZZZ`, since it would loop forever from there.
     pub fn walk(&self) -> Result<usize, NetworkParseError> {
-        let mut steps = 0;
-        let mut current_step = &String::from("AAA");
-        let last_node = &String::from("ZZZ");
-        let mut direction_iter = self.directions.iter();
+        self.walk_from(self.node("AAA")?, self.node("ZZZ")?, |_, _| {})
+    }
+
+    /// Steps of the walk from `start` to `goal`, as the node left and the direction taken.
+    pub fn walk_path(
+        &self,
+        start: &str,
+        goal: &str,
+    ) -> Result<Vec<(&str, &Direction)>, NetworkParseError> {
+        let mut path: Vec<(&str, &Direction)> = vec![];
+
+        self.walk_from(self.node(start)?, self.node(goal)?, |node, direction| {
+            path.push((node, direction))
+        })?;
+
+        Ok(path)
+    }
 
-        while current_step != last_node {
-            let mut direction = direction_iter.next();
+    /// Lazily follows the directions from `start` forever.
+    pub fn trace(&self, start: &str) -> Result<Trace<'_>, NetworkParseError> {
+        self.trace_from(self.node(start)?)
+    }
+
+    fn trace_from(&self, start: usize) -> Result<Trace<'_>, NetworkParseError> {
+        if self.directions.is_empty() {
+            return Err(NetworkParseError::InvalidInputError);
+        }
+
+        Ok(Trace {
+            network: self,
+            node: start,
+            step: 0,
+        })
+    }
+
+    fn walk_from<'a>(
+        &'a self,
+        start: usize,
+        goal: usize,
+        mut visit: impl FnMut(&'a str, &'a Direction),
+    ) -> Result<usize, NetworkParseError> {
+        let mut trace = self.trace_from(start)?;
+        // There are only this many (node, direction index) states, so a walk still going after
+        // that many steps has repeated one of them and loops forever without the goal.
+        let states = self.ids.len().saturating_mul(self.directions.len());
 
-            if direction.is_none() {
-                direction_iter = self.directions.iter();
-                direction = direction_iter.next();
+        loop {
+            let (step, direction, node) = trace.advance();
+
+            if node == goal {
+                return Ok(step);
             }
 
-            let node = self
-                .node_index
-                .get(current_step)
-                .and_then(|i| self.nodes.get(*i))
-                .ok_or(NetworkParseError::InvalidInputError)?;
+            if step >= states {
+                return Err(NetworkParseError::GoalUnreachable);
+            }
 
-            if let Some(direction) = direction {
-                current_step = match direction {
-                    Direction::Left => &node.left,
-                    Direction::Right => &node.right,
-                };
-                steps += 1;
-            } else {
-                return Err(NetworkParseError::InvalidInputError);
+            visit(&self.ids[node], direction);
+        }
+    }
+
+    fn node(&self, id: &str) -> Result<usize, NetworkParseError> {
+        self.node_index
+            .get(id)
+            .map(|i| *i as usize)
+            .ok_or_else(|| NetworkParseError::MissingNode(id.to_owned()))
+    }
+
+    fn next(&self, node: usize, direction: &Direction) -> usize {
+        self.links[node][direction.side()] as usize
+    }
+
+    /// Follows the directions from `start` until a (node, direction index) state repeats,
+    /// recording every step on which the walk stands on a goal node.
+    pub fn cycle(
+        &self,
+        start: &str,
+        goal: impl Fn(&str) -> bool,
+    ) -> Result<WalkCycle, NetworkParseError> {
+        self.cycle_from(self.node(start)?, &goal)
+    }
+
+    fn cycle_from(
+        &self,
+        start: usize,
+        goal: &impl Fn(&str) -> bool,
+    ) -> Result<WalkCycle, NetworkParseError> {
+        if self.directions.is_empty() {
+            return Err(NetworkParseError::InvalidInputError);
+        }
+
+        let mut seen: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut hits: Vec<usize> = vec![];
+        let mut node = start;
+        let mut step = 0;
+
+        loop {
+            let direction_index = step % self.directions.len();
+
+            if let Some(first) = seen.insert((node, direction_index), step) {
+                let (tail_hits, cycle_hits) = hits.into_iter().partition(|hit| *hit < first);
+
+                return Ok(WalkCycle {
+                    tail: first,
+                    length: step - first,
+                    tail_hits,
+                    cycle_hits,
+                });
+            }
+
+            if goal(&self.ids[node]) {
+                hits.push(step);
+            }
+
+            node = self.next(node, &self.directions[direction_index]);
+            step += 1;
+        }
+    }
+
+    /// Upper bound on the candidate steps `ghost_walk` keeps while combining cycles.
+    const MAX_CANDIDATES: usize = 1 << 16;
+
+    /// Walks from every node matching `start` at once, counting the steps until all of them
+    /// stand on a node matching `goal`. Each walk is reduced to its cycle, and the cycles are
+    /// combined with the Chinese remainder theorem instead of being simulated.
+    ///
+    /// Every goal hit inside a cycle is a separate congruence, so the candidates can grow with
+    /// the product of the hits per cycle. Cycles are combined from the fewest hits up so
+    /// incompatible candidates are dropped early, and the walk fails with
+    /// [`NetworkParseError::TooManyCandidates`] rather than exceed [`Self::MAX_CANDIDATES`].
+    pub fn ghost_walk(
+        &self,
+        start: impl Fn(&str) -> bool,
+        goal: impl Fn(&str) -> bool,
+    ) -> Result<usize, NetworkParseError> {
+        let cycles = (0..self.ids.len())
+            .filter(|node| start(&self.ids[*node]))
+            .map(|node| self.cycle_from(node, &goal))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let longest_tail = cycles
+            .iter()
+            .max_by_key(|cycle| cycle.tail)
+            .ok_or(NetworkParseError::InvalidInputError)?;
+
+        // Until every walk is periodic, the one with the longest tail only hits its tail hits.
+        if let Some(step) = longest_tail
+            .tail_hits
+            .iter()
+            .find(|step| cycles.iter().all(|cycle| cycle.is_hit(**step)))
+        {
+            return Ok(*step);
+        }
+
+        let mut periodic = cycles.iter().collect::<Vec<_>>();
+        periodic.sort_by_key(|cycle| cycle.cycle_hits.len());
+
+        let mut congruences: Vec<(u128, u128)> = vec![(0, 1)];
+
+        for cycle in periodic {
+            let length = cycle.length as u128;
+
+            if congruences.len() * cycle.cycle_hits.len() > Self::MAX_CANDIDATES {
+                return Err(NetworkParseError::TooManyCandidates(Self::MAX_CANDIDATES));
+            }
+
+            congruences = congruences
+                .iter()
+                .flat_map(|congruence| {
+                    cycle.cycle_hits.iter().map(move |hit| {
+                        combine_congruences(*congruence, (*hit as u128 % length, length))
+                    })
+                })
+                .filter_map(Result::transpose)
+                .collect::<Result<_, _>>()?;
+            congruences.sort();
+            congruences.dedup();
+        }
+
+        let periodic_from = longest_tail.tail as u128;
+
+        let step = congruences
+            .into_iter()
+            .map(|(residue, modulus)| {
+                if residue >= periodic_from {
+                    return Ok(residue);
+                }
+
+                (periodic_from - residue)
+                    .div_ceil(modulus)
+                    .checked_mul(modulus)
+                    .and_then(|offset| offset.checked_add(residue))
+                    .ok_or(NetworkParseError::CycleOverflow)
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .min()
+            .ok_or(NetworkParseError::GoalUnreachable)?;
+
+        Ok(usize::try_from(step)?)
+    }
+}
+
+impl Network {
+    /// Writes the network as a Graphviz DOT digraph with `L`/`R` labelled edges. Start and goal
+    /// nodes are filled, and the nodes and edges of `path` (as returned by `walk_path`) are
+    /// coloured. Fails with `MissingNode` before writing anything if `path` leaves the network.
+    pub fn write_dot(
+        &self,
+        out: &mut impl Write,
+        start: impl Fn(&str) -> bool,
+        goal: impl Fn(&str) -> bool,
+        path: &[(&str, &Direction)],
+    ) -> Result<(), NetworkParseError> {
+        let path_edges: HashSet<(&str, usize)> = path
+            .iter()
+            .map(|(id, direction)| Ok((self.ids[self.node(id)?].as_str(), direction.side())))
+            .collect::<Result<_, NetworkParseError>>()?;
+        let mut path_nodes: HashSet<&str> = path_edges.iter().map(|(id, _)| *id).collect();
+        if let Some((id, direction)) = path.last() {
+            let last = self.next(self.node(id)?, direction);
+            path_nodes.insert(&self.ids[last]);
+        }
+
+        writeln!(out, "digraph network {{")?;
+
+        for id in self.ids.iter() {
+            let mut attributes: Vec<&str> = vec![];
+
+            if start(id) {
+                attributes.extend(["style=filled", "fillcolor=palegreen"]);
+            } else if goal(id) {
+                attributes.extend(["style=filled", "fillcolor=lightcoral"]);
+            }
+
+            if path_nodes.contains(id.as_str()) {
+                attributes.extend(["color=blue", "penwidth=2"]);
+            }
+
+            write!(out, "    {}", quote(id))?;
+            if !attributes.is_empty() {
+                write!(out, " [{}]", attributes.join(", "))?;
+            }
+            writeln!(out, ";")?;
+        }
+
+        for (id, links) in self.ids.iter().zip(self.links.iter()) {
+            for (side, label) in ["L", "R"].iter().enumerate() {
+                let to = &self.ids[links[side] as usize];
+
+                write!(out, "    {} -> {} [label={}", quote(id), quote(to), label)?;
+                if path_edges.contains(&(id.as_str(), side)) {
+                    write!(out, ", color=blue, penwidth=2")?;
+                }
+                writeln!(out, "];")?;
+            }
+        }
+
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    pub fn to_dot(
+        &self,
+        start: impl Fn(&str) -> bool,
+        goal: impl Fn(&str) -> bool,
+        path: &[(&str, &Direction)],
+    ) -> Result<String, NetworkParseError> {
+        let mut dot = String::new();
+
+        self.write_dot(&mut dot, start, goal, path)?;
+
+        Ok(dot)
+    }
+}
+
+/// Graph queries over the left and right links, ignoring the directions.
+impl Network {
+    /// Breadth first search from `from`, returning the node each reached node was reached from.
+    fn parents(&self, from: usize) -> Vec<Option<usize>> {
+        let mut parents: Vec<Option<usize>> = vec![None; self.ids.len()];
+        let mut queue: VecDeque<usize> = VecDeque::from([from]);
+        parents[from] = Some(from);
+
+        while let Some(node) = queue.pop_front() {
+            for next in self.links[node] {
+                let next = next as usize;
+
+                if parents[next].is_none() {
+                    parents[next] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        parents
+    }
+
+    /// Nodes on a shortest path from `from` to `to`, both included, or `None` if `to` can't be
+    /// reached.
+    pub fn shortest_path(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Result<Option<Vec<&str>>, NetworkParseError> {
+        let from = self.node(from)?;
+        let to = self.node(to)?;
+        let parents = self.parents(from);
+
+        if parents[to].is_none() {
+            return Ok(None);
+        }
+
+        let mut path: Vec<&str> = vec![&self.ids[to]];
+        let mut node = to;
+
+        while node != from {
+            node = parents[node].expect("Reached nodes should have a parent");
+            path.push(&self.ids[node]);
+        }
+
+        path.reverse();
+
+        Ok(Some(path))
+    }
+
+    /// Every node reachable from `from`, including `from` itself.
+    pub fn reachable(&self, from: &str) -> Result<HashSet<&str>, NetworkParseError> {
+        let parents = self.parents(self.node(from)?);
+
+        Ok(parents
+            .iter()
+            .enumerate()
+            .filter(|(_, parent)| parent.is_some())
+            .map(|(node, _)| self.ids[node].as_str())
+            .collect())
+    }
+
+    /// Strongly connected components in reverse topological order, found with an iterative
+    /// version of Tarjan's algorithm so large networks don't overflow the stack.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&str>> {
+        let mut order: Vec<Option<usize>> = vec![None; self.ids.len()];
+        let mut low_link: Vec<usize> = vec![0; self.ids.len()];
+        let mut on_stack: Vec<bool> = vec![false; self.ids.len()];
+        let mut stack: Vec<usize> = vec![];
+        let mut components: Vec<Vec<&str>> = vec![];
+        let mut next_order = 0;
+
+        for root in 0..self.ids.len() {
+            if order[root].is_some() {
+                continue;
+            }
+
+            // Each frame is a node and the side of its next link to visit.
+            let mut frames: Vec<(usize, usize)> = vec![(root, 0)];
+            order[root] = Some(next_order);
+            low_link[root] = next_order;
+            next_order += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some((node, side)) = frames.pop() {
+                if side < 2 {
+                    frames.push((node, side + 1));
+
+                    let next = self.links[node][side] as usize;
+
+                    match order[next] {
+                        None => {
+                            order[next] = Some(next_order);
+                            low_link[next] = next_order;
+                            next_order += 1;
+                            stack.push(next);
+                            on_stack[next] = true;
+                            frames.push((next, 0));
+                        }
+                        Some(seen) if on_stack[next] => {
+                            low_link[node] = low_link[node].min(seen);
+                        }
+                        Some(_) => {}
+                    }
+
+                    continue;
+                }
+
+                if let Some((parent, _)) = frames.last() {
+                    low_link[*parent] = low_link[*parent].min(low_link[node]);
+                }
+
+                if Some(low_link[node]) == order[node] {
+                    let mut component: Vec<&str> = vec![];
+
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(&self.ids[member]);
+
+                        if member == node {
+                            break;
+                        }
+                    }
+
+                    components.push(component);
+                }
             }
         }
 
-        Ok(steps)
+        components
+    }
+}
+
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// A walk through a [`Network`], yielding the step, the direction taken and the node it was
+/// taken from.
+#[derive(Clone)]
+pub struct Trace<'a> {
+    network: &'a Network,
+    node: usize,
+    step: usize,
+}
+
+impl<'a> Trace<'a> {
+    /// Takes the next step, returning the node it was taken from as an index.
+    fn advance(&mut self) -> (usize, &'a Direction, usize) {
+        let network = self.network;
+        let direction = &network.directions[self.step % network.directions.len()];
+        let item = (self.step, direction, self.node);
+
+        self.node = network.next(self.node, direction);
+        self.step += 1;
+
+        item
+    }
+}
+
+impl<'a> Iterator for Trace<'a> {
+    type Item = (usize, &'a Direction, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (step, direction, node) = self.advance();
+
+        Some((step, direction, self.network.ids[node].as_str()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// Shape of a walk over the network. A walk state is a node together with the index into the
+/// directions, so every walk ends up repeating the same `length` states after `tail` steps.
+#[derive(Debug, PartialEq)]
+pub struct WalkCycle {
+    pub tail: usize,
+    pub length: usize,
+    /// Steps before the walk becomes periodic on which it stands on a goal.
+    pub tail_hits: Vec<usize>,
+    /// Steps within the first pass through the cycle on which it stands on a goal. The walk
+    /// stands on a goal again every `length` steps after each of them.
+    pub cycle_hits: Vec<usize>,
+}
+
+impl WalkCycle {
+    pub fn is_reachable(&self) -> bool {
+        !self.tail_hits.is_empty() || !self.cycle_hits.is_empty()
+    }
+
+    pub fn first_hit(&self) -> Option<usize> {
+        self.tail_hits.first().or(self.cycle_hits.first()).copied()
+    }
+
+    pub fn is_hit(&self, step: usize) -> bool {
+        if step < self.tail {
+            return self.tail_hits.contains(&step);
+        }
+
+        self.cycle_hits
+            .contains(&(self.tail + (step - self.tail) % self.length))
+    }
+}
+
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+
+    let (gcd, x, y) = extended_gcd(b, a % b);
+
+    (gcd, y, x - (a / b) * y)
+}
+
+/// Merges `x = r1 (mod m1)` and `x = r2 (mod m2)` into a single congruence modulo their
+/// least common multiple, if the two agree. Fails with `CycleOverflow` when the least common
+/// multiple doesn't fit.
+fn combine_congruences(
+    (r1, m1): (u128, u128),
+    (r2, m2): (u128, u128),
+) -> Result<Option<(u128, u128)>, NetworkParseError> {
+    let signed = |value: u128| i128::try_from(value).map_err(|_| NetworkParseError::CycleOverflow);
+
+    let (gcd, inverse, _) = extended_gcd(signed(m1)?, signed(m2)?);
+    let difference = signed(r2)? - signed(r1)?;
+
+    if difference % gcd != 0 {
+        return Ok(None);
+    }
+
+    let m2_reduced = signed(m2)? / gcd;
+    let k = (difference / gcd % m2_reduced)
+        .checked_mul(inverse)
+        .ok_or(NetworkParseError::CycleOverflow)?
+        .rem_euclid(m2_reduced) as u128;
+    let modulus = (m1 / gcd as u128)
+        .checked_mul(m2)
+        .ok_or(NetworkParseError::CycleOverflow)?;
+
+    // `k` is below `m2 / gcd`, so `m1 * k` stays below the modulus.
+    let residue = (r1 % modulus + m1 * k % modulus) % modulus;
+
+    Ok(Some((residue, modulus)))
+}
+
+impl Network {
+    /// Parses a network line by line from `reader`, without reading it into memory first.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, NetworkParseError> {
+        Self::from_lines(
+            reader
+                .lines()
+                .map(|line| line.map_err(NetworkParseError::from)),
+        )
+    }
+
+    fn from_lines<S: AsRef<str>>(
+        lines: impl Iterator<Item = Result<S, NetworkParseError>>,
+    ) -> Result<Self, NetworkParseError> {
+        let mut lines = lines
+            .enumerate()
+            .map(|(i, text)| text.map(|text| (i + 1, text)));
+
+        let directions = match lines.next().transpose()? {
+            Some((_, text)) if !text.as_ref().is_empty() => text
+                .as_ref()
+                .chars()
+                .map(Direction::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| NetworkParseError::InvalidDirections {
+                    line: 1,
+                    text: text.as_ref().to_owned(),
+                })?,
+            _ => return Err(NetworkParseError::EmptyDirections { line: 1 }),
+        };
+
+        if let Some((line, text)) = lines.next().transpose()? {
+            if !text.as_ref().is_empty() {
+                return Err(NetworkParseError::InvalidLine {
+                    line,
+                    text: text.as_ref().to_owned(),
+                });
+            }
+        }
+
+        let mut defined: HashSet<String> = HashSet::new();
+        let mut nodes: Vec<(usize, Node)> = vec![];
+
+        for line in lines {
+            let (line, text) = line?;
+
+            if text.as_ref().is_empty() {
+                continue;
+            }
+
+            let node: Node = text
+                .as_ref()
+                .parse()
+                .map_err(|_| NetworkParseError::InvalidLine {
+                    line,
+                    text: text.as_ref().to_owned(),
+                })?;
+
+            if !defined.insert(node.index.clone()) {
+                return Err(NetworkParseError::DuplicateNode {
+                    line,
+                    text: text.as_ref().to_owned(),
+                    id: node.index,
+                });
+            }
+
+            nodes.push((line, node));
+        }
+
+        // Node lines only parse in their canonical form, so the text is rebuilt from the node
+        // instead of keeping every line around.
+        for (line, node) in nodes.iter() {
+            if let Some(id) = [&node.left, &node.right]
+                .into_iter()
+                .find(|id| !defined.contains(*id))
+            {
+                return Err(NetworkParseError::DanglingReference {
+                    line: *line,
+                    text: node.to_string(),
+                    id: id.to_owned(),
+                });
+            }
+        }
+
+        Network::new(
+            directions,
+            nodes.into_iter().map(|(_, node)| node).collect(),
+        )
+    }
+}
+
+impl FromStr for Network {
+    type Err = NetworkParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Network::from_lines(s.lines().map(Ok))
+    }
+}
+
+impl TryFrom<&str> for Network {
+    type Error = NetworkParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
     }
 }
 
@@ -119,27 +793,633 @@ impl TryFrom<String> for Network {
     type Error = NetworkParseError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let mut lines = value.lines();
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_walk() {
+        let network: Network = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(network.walk().expect("Should find answer"), 6);
+    }
+
+    #[test]
+    fn test_network_trace() {
+        let network: Network = "LLR
 
-        let directions = lines.next().expect("Should exist");
-        let directions: Vec<Direction> = directions
-            .chars()
-            .map(Direction::try_from)
-            .collect::<Result<Vec<_>, _>>()
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"
+            .to_owned()
+            .try_into()
             .expect("Should parse");
 
-        lines.next().expect("Should skip next line");
+        let trace = network.trace("AAA").expect("Should trace");
+        let steps = trace
+            .take(4)
+            .map(|(step, direction, node)| (step, *direction, node))
+            .collect::<Vec<_>>();
 
-        let mut node_index_map: HashMap<String, usize> = HashMap::new();
-        let mut nodes: Vec<Node> = vec![];
+        assert_eq!(
+            steps,
+            vec![
+                (0, Direction::Left, "AAA"),
+                (1, Direction::Left, "BBB"),
+                (2, Direction::Right, "AAA"),
+                (3, Direction::Left, "BBB"),
+            ]
+        );
 
-        for node in lines {
-            let node: Node = node.parse().expect("Should parse");
-            node_index_map.insert(node.index.to_owned(), nodes.len());
+        let mut trace = network.trace("AAA").expect("Should trace");
 
-            nodes.push(node);
+        assert_eq!(trace.position(|(_, _, node)| node == "ZZZ"), Some(6));
+        assert_eq!(
+            trace.next().map(|(step, _, node)| (step, node)),
+            Some((7, "ZZZ"))
+        );
+        assert!(matches!(
+            network.trace("CCC"),
+            Err(NetworkParseError::MissingNode(id)) if id == "CCC"
+        ));
+    }
+
+    #[test]
+    fn test_network_walk_unreachable() {
+        let network: Network = "LR
+
+AAA = (BBB, CCC)
+BBB = (AAA, AAA)
+CCC = (CCC, CCC)
+ZZZ = (ZZZ, ZZZ)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        assert!(matches!(
+            network.walk(),
+            Err(NetworkParseError::GoalUnreachable)
+        ));
+    }
+
+    #[test]
+    fn test_network_walk_missing_nodes() {
+        let network: Network = "L
+
+AAA = (AAA, AAA)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        assert!(matches!(
+            network.walk(),
+            Err(NetworkParseError::MissingNode(id)) if id == "ZZZ"
+        ));
+
+        let network: Network = "L
+
+ZZZ = (ZZZ, ZZZ)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        assert!(matches!(
+            network.walk(),
+            Err(NetworkParseError::MissingNode(id)) if id == "AAA"
+        ));
+    }
+
+    #[test]
+    fn test_network_ghost_walk_unreachable() {
+        let network: Network = "L
+
+A1 = (Z1, Z1)
+Z1 = (A1, A1)
+A2 = (B2, B2)
+B2 = (Z2, Z2)
+Z2 = (A2, A2)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        // The first ghost is on a goal on odd steps only, the second on steps 2, 5, 8, ...
+        assert_eq!(
+            network
+                .ghost_walk(|id| id.starts_with('A'), |id| id.starts_with('Z'))
+                .expect("Should find answer"),
+            5
+        );
+        assert!(matches!(
+            network.ghost_walk(|id| id.starts_with('A'), |id| id == "B2"),
+            Err(NetworkParseError::GoalUnreachable)
+        ));
+    }
+
+    #[test]
+    fn test_network_ghost_walk() {
+        let network: Network = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(
+            network
+                .ghost_walk(|id| id.ends_with('A'), |id| id.ends_with('Z'))
+                .expect("Should find answer"),
+            6
+        );
+    }
+
+    #[test]
+    fn test_network_ghost_walk_offsets() {
+        // The first ghost hits its goal on steps 1, 4, 7, ... and the second on steps 2, 7, 12, ...
+        let network: Network = "L
+
+A1 = (B1, B1)
+B1 = (C1, C1)
+C1 = (D1, D1)
+D1 = (B1, B1)
+A2 = (B2, B2)
+B2 = (C2, C2)
+C2 = (D2, D2)
+D2 = (E2, E2)
+E2 = (F2, F2)
+F2 = (G2, G2)
+G2 = (C2, C2)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        let goals = ["B1", "C2"];
+
+        assert_eq!(
+            network
+                .ghost_walk(|id| id.starts_with('A'), |id| goals.contains(&id))
+                .expect("Should find answer"),
+            7
+        );
+    }
+
+    #[test]
+    fn test_network_ghost_walk_tail_hit() {
+        // Both ghosts pass a goal on step 1 before settling in cycles that never line up.
+        let network: Network = "L
+
+A1 = (Z1, Z1)
+Z1 = (B1, B1)
+B1 = (B1, B1)
+A2 = (Z2, Z2)
+Z2 = (C2, C2)
+C2 = (D2, D2)
+D2 = (C2, C2)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(
+            network
+                .ghost_walk(|id| id.starts_with('A'), |id| id.starts_with('Z'))
+                .expect("Should find answer"),
+            1
+        );
+    }
+
+    #[test]
+    fn test_network_ghost_walk_too_many_candidates() {
+        // Every node but the starts is a goal, on loops of coprime lengths, so every
+        // combination of hits is compatible.
+        let mut input = String::from("L\n\n");
+
+        for length in [7, 11, 13, 17, 19] {
+            for i in 0..length {
+                let next = format!("L{}N{}", length, (i + 1) % length);
+                input.push_str(&format!("L{}N{} = ({}, {})\n", length, i, next, next));
+            }
         }
 
-        Ok(Network::new(directions, nodes, node_index_map))
+        let network: Network = input.parse().expect("Should parse");
+
+        assert!(matches!(
+            network.ghost_walk(|id| id.ends_with("N0"), |id| !id.ends_with("N0")),
+            Err(NetworkParseError::TooManyCandidates(_))
+        ));
+        assert_eq!(
+            network
+                .ghost_walk(|id| id.ends_with("N0"), |id| id.ends_with("N1"))
+                .expect("Should find answer"),
+            1
+        );
+    }
+
+    #[test]
+    fn test_network_cycle() {
+        let network: Network = "L
+
+A1 = (B1, B1)
+B1 = (C1, C1)
+C1 = (D1, D1)
+D1 = (B1, B1)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        let cycle = network
+            .cycle("A1", |id| id == "C1")
+            .expect("Should find cycle");
+
+        assert_eq!(
+            cycle,
+            WalkCycle {
+                tail: 1,
+                length: 3,
+                tail_hits: vec![],
+                cycle_hits: vec![2],
+            }
+        );
+        assert!(cycle.is_reachable());
+        assert_eq!(cycle.first_hit(), Some(2));
+        assert!(cycle.is_hit(8));
+        assert!(!cycle.is_hit(9));
+
+        let cycle = network
+            .cycle("A1", |id| id == "A1")
+            .expect("Should find cycle");
+
+        assert_eq!(cycle.tail_hits, vec![0]);
+        assert!(!cycle.is_hit(3));
+
+        let cycle = network
+            .cycle("B1", |id| id == "A1")
+            .expect("Should find cycle");
+
+        assert!(!cycle.is_reachable());
+        assert_eq!(cycle.first_hit(), None);
+    }
+
+    #[test]
+    fn test_network_cycle_direction_index() {
+        // The node repeats after two steps, but the state only repeats once the directions do.
+        let network: Network = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        let cycle = network
+            .cycle("AAA", |id| id == "ZZZ")
+            .expect("Should find cycle");
+
+        assert_eq!(cycle.first_hit(), Some(6));
+        assert_eq!(cycle.tail, 6);
+        assert_eq!(cycle.length, 3);
+    }
+
+    #[test]
+    fn test_network_interned_links() {
+        let network: Network = "LR
+
+AAA = (BBB, ZZZ)
+BBB = (AAA, BBB)
+ZZZ = (ZZZ, AAA)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(network.ids, vec!["AAA", "BBB", "ZZZ"]);
+        assert_eq!(network.links, vec![[1, 2], [0, 1], [2, 0]]);
+    }
+
+    #[test]
+    fn test_network_dangling_reference() {
+        let network: Result<Network, _> = "L
+
+AAA = (BBB, AAA)"
+            .to_owned()
+            .try_into();
+
+        let error = network.err().expect("Should not parse");
+
+        assert!(matches!(
+            &error,
+            NetworkParseError::DanglingReference { line: 3, id, .. } if id == "BBB"
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Node `BBB` referenced on line 3 doesn't exist: `AAA = (BBB, AAA)`"
+        );
+    }
+
+    #[test]
+    fn test_network_from_borrowed_input() {
+        let input = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+
+        let parsed: Network = input.parse().expect("Should parse");
+        let converted = Network::try_from(input).expect("Should parse");
+        let read = Network::from_reader(input.as_bytes()).expect("Should read");
+
+        assert_eq!(parsed.walk().expect("Should walk"), 6);
+        assert_eq!(converted.walk().expect("Should walk"), 6);
+        assert_eq!(read.walk().expect("Should walk"), 6);
+    }
+
+    #[test]
+    fn test_network_from_reader_errors() {
+        let input: &[u8] = b"LR\n\nAAA = (AAA, AAA)\nBBB = (AAA, \xff)\n";
+
+        assert!(matches!(
+            Network::from_reader(input),
+            Err(NetworkParseError::Io(_))
+        ));
+        assert!(matches!(
+            Network::from_reader("LR\n\nAAA = (AAA, CCC)".as_bytes()),
+            Err(NetworkParseError::DanglingReference { line: 3, id, .. }) if id == "CCC"
+        ));
+    }
+
+    #[test]
+    fn test_network_parse_errors() {
+        let parse = |input: &str| input.parse::<Network>().err();
+
+        assert!(matches!(
+            parse(""),
+            Some(NetworkParseError::EmptyDirections { line: 1 })
+        ));
+        assert!(matches!(
+            parse("\n\nAAA = (AAA, AAA)"),
+            Some(NetworkParseError::EmptyDirections { line: 1 })
+        ));
+        assert!(matches!(
+            parse("LRX\n\nAAA = (AAA, AAA)"),
+            Some(NetworkParseError::InvalidDirections { line: 1, text }) if text == "LRX"
+        ));
+        assert!(matches!(
+            parse("LR\nAAA = (AAA, AAA)"),
+            Some(NetworkParseError::InvalidLine { line: 2, .. })
+        ));
+        assert!(matches!(
+            parse("LR\n\nAAA = (AAA, AAA)\nBBB = AAA, AAA"),
+            Some(NetworkParseError::InvalidLine { line: 4, text }) if text == "BBB = AAA, AAA"
+        ));
+        assert!(matches!(
+            parse("LR\n\nAAA = (AAA, AAA)\nBBB (AAA, AAA)"),
+            Some(NetworkParseError::InvalidLine { line: 4, .. })
+        ));
+        assert!(matches!(
+            parse("LR\n\nAAA = (AAA)"),
+            Some(NetworkParseError::InvalidLine { line: 3, .. })
+        ));
+        assert!(matches!(
+            parse("LR\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)"),
+            Some(NetworkParseError::DuplicateNode { line: 4, id, .. }) if id == "AAA"
+        ));
+        assert!(parse("LR\n\nAAA = (AAA, AAA)\n").is_none());
+    }
+
+    #[test]
+    fn test_network_walk_path() {
+        let network: Network = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        let path = network.walk_path("AAA", "ZZZ").expect("Should find path");
+
+        assert_eq!(path.len(), 6);
+        assert!(matches!(path[5], ("BBB", Direction::Right)));
+    }
+
+    #[test]
+    fn test_network_to_dot() {
+        let network: Network = "LR
+
+AAA = (BBB, ZZZ)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        let path = network.walk_path("AAA", "ZZZ").expect("Should find path");
+        let dot = network
+            .to_dot(|id| id == "AAA", |id| id == "ZZZ", &path)
+            .expect("Should write");
+
+        assert_eq!(
+            dot,
+            r#"digraph network {
+    "AAA" [style=filled, fillcolor=palegreen, color=blue, penwidth=2];
+    "BBB" [color=blue, penwidth=2];
+    "ZZZ" [style=filled, fillcolor=lightcoral, color=blue, penwidth=2];
+    "AAA" -> "BBB" [label=L, color=blue, penwidth=2];
+    "AAA" -> "ZZZ" [label=R];
+    "BBB" -> "AAA" [label=L];
+    "BBB" -> "ZZZ" [label=R, color=blue, penwidth=2];
+    "ZZZ" -> "ZZZ" [label=L];
+    "ZZZ" -> "ZZZ" [label=R];
+}
+"#
+        );
+
+        assert!(matches!(
+            network.to_dot(|_| false, |_| false, &[("QQQ", &Direction::Left)]),
+            Err(NetworkParseError::MissingNode(id)) if id == "QQQ"
+        ));
+    }
+
+    #[test]
+    fn test_network_shortest_path() {
+        let network: Network = "L
+
+AAA = (BBB, CCC)
+BBB = (DDD, DDD)
+CCC = (ZZZ, AAA)
+DDD = (ZZZ, DDD)
+ZZZ = (ZZZ, ZZZ)
+XXX = (AAA, AAA)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        assert_eq!(
+            network
+                .shortest_path("AAA", "ZZZ")
+                .expect("Nodes should exist"),
+            Some(vec!["AAA", "CCC", "ZZZ"])
+        );
+        assert_eq!(
+            network
+                .shortest_path("AAA", "AAA")
+                .expect("Nodes should exist"),
+            Some(vec!["AAA"])
+        );
+        assert_eq!(
+            network
+                .shortest_path("ZZZ", "AAA")
+                .expect("Nodes should exist"),
+            None
+        );
+        assert!(matches!(
+            network.shortest_path("AAA", "YYY"),
+            Err(NetworkParseError::MissingNode(_))
+        ));
+
+        let reachable = network.reachable("BBB").expect("Node should exist");
+        assert_eq!(reachable, HashSet::from(["BBB", "DDD", "ZZZ"]));
+        assert_eq!(
+            network.reachable("XXX").expect("Node should exist").len(),
+            6
+        );
+    }
+
+    #[test]
+    fn test_network_strongly_connected_components() {
+        let network: Network = "L
+
+AAA = (BBB, CCC)
+BBB = (AAA, DDD)
+CCC = (DDD, DDD)
+DDD = (EEE, EEE)
+EEE = (DDD, ZZZ)
+ZZZ = (ZZZ, ZZZ)"
+            .to_owned()
+            .try_into()
+            .expect("Should parse");
+
+        let mut components = network
+            .strongly_connected_components()
+            .into_iter()
+            .map(|mut component| {
+                component.sort();
+                component
+            })
+            .collect::<Vec<_>>();
+
+        // Components come out in reverse topological order.
+        assert_eq!(components[0], vec!["ZZZ"]);
+        assert_eq!(components[components.len() - 1], vec!["AAA", "BBB"]);
+
+        components.sort();
+        assert_eq!(
+            components,
+            vec![
+                vec!["AAA", "BBB"],
+                vec!["CCC"],
+                vec!["DDD", "EEE"],
+                vec!["ZZZ"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_network_strongly_connected_components_matches_reachable() {
+        let mut seed: u64 = 42;
+        let mut random = |bound: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) % bound
+        };
+
+        let input = (0..60)
+            .map(|i| format!("N{} = (N{}, N{})", i, random(60), random(60)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let network: Network = format!("L\n\n{}", input).try_into().expect("Should parse");
+
+        let components = network.strongly_connected_components();
+        let component_of = components
+            .iter()
+            .enumerate()
+            .flat_map(|(i, component)| component.iter().map(move |id| (*id, i)))
+            .collect::<HashMap<_, _>>();
+
+        assert_eq!(component_of.len(), 60);
+
+        for a in network.ids.iter() {
+            let from_a = network.reachable(a).expect("Node should exist");
+
+            for b in network.ids.iter() {
+                let mutual = from_a.contains(b.as_str())
+                    && network
+                        .reachable(b)
+                        .expect("Node should exist")
+                        .contains(a.as_str());
+
+                assert_eq!(component_of[a.as_str()] == component_of[b.as_str()], mutual);
+            }
+        }
+    }
+
+    #[test]
+    fn test_combine_congruences() {
+        let combine = |first, second| combine_congruences(first, second).expect("Should fit");
+
+        assert_eq!(combine((0, 1), (2, 3)), Some((2, 3)));
+        assert_eq!(combine((2, 3), (3, 5)), Some((8, 15)));
+        assert_eq!(combine((1, 4), (3, 6)), Some((9, 12)));
+        assert_eq!(combine((1, 4), (2, 6)), None);
+        assert!(matches!(
+            combine_congruences((0, u128::MAX), (0, 3)),
+            Err(NetworkParseError::CycleOverflow)
+        ));
+        assert!(matches!(
+            combine_congruences((0, 1 << 100), (1, (1 << 100) + 1)),
+            Err(NetworkParseError::CycleOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_network_ghost_walk_cycle_overflow() {
+        // The product of the twenty prime cycle lengths doesn't fit in a u128.
+        let primes = [
+            101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181,
+            191, 193, 197,
+        ];
+        let mut input = String::from("L\n\n");
+
+        for length in primes {
+            for i in 0..length {
+                let id = |i: usize| match i {
+                    i if i == length - 1 => format!("G{}Z", length),
+                    i => format!("G{}N{}", length, i),
+                };
+                let next = id((i + 1) % length);
+                input.push_str(&format!("{} = ({}, {})\n", id(i), next, next));
+            }
+        }
+
+        let network: Network = input.parse().expect("Should parse");
+
+        assert!(matches!(
+            network.ghost_walk(|id| id.ends_with("N0"), |id| id.ends_with('Z')),
+            Err(NetworkParseError::CycleOverflow)
+        ));
     }
 }
@@ -0,0 +1,161 @@
+use std::{fmt::Display, str::FromStr};
+
+use thiserror::Error;
+
+use crate::{
+    boat_race::RaceParseError,
+    callibration_value::CalibrationValueFromStrError,
+    camel_hand::CamelHandParseError,
+    cube_game::CubeGameFromStrError,
+    days,
+    engine_schematic::SchematicParseError,
+    farm_map::{AlmanacParseError, FarmMapParseError},
+    network::NetworkParseError,
+    scratch_card::ScratchCardFromStrError,
+};
+
+#[derive(Debug, Error)]
+pub enum SolverError {
+    #[error("Invalid input error")]
+    InvalidInputError,
+    #[error("Part is not implemented")]
+    NotImplemented,
+    #[error("Day {0} is not implemented")]
+    UnknownDay(u8),
+    #[error("Can't parse calibration value")]
+    CalibrationValue(#[from] CalibrationValueFromStrError),
+    #[error("Can't parse cube game")]
+    CubeGame(#[from] CubeGameFromStrError),
+    #[error("Can't parse engine schematic")]
+    Schematic(#[from] SchematicParseError),
+    #[error("Can't parse scratch card")]
+    ScratchCard(#[from] ScratchCardFromStrError),
+    #[error("Can't parse farm map")]
+    FarmMap(#[from] FarmMapParseError),
+    #[error("Can't parse almanac")]
+    Almanac(#[from] AlmanacParseError),
+    #[error("Can't parse race sheet")]
+    Race(#[from] RaceParseError),
+    #[error("Can't parse camel hand")]
+    CamelHand(#[from] CamelHandParseError),
+    #[error("Can't parse network")]
+    Network(#[from] NetworkParseError),
+}
+
+/// A single day of the puzzle: parses the raw input once and answers both parts from it.
+pub trait Solver {
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, SolverError>;
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, SolverError>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, SolverError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = SolverError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(SolverError::InvalidInputError),
+        }
+    }
+}
+
+type SolveFn = fn(&str) -> Result<String, SolverError>;
+
+/// Type-erased entry of the day registry, so days with different answer types can be run uniformly.
+pub struct Puzzle {
+    pub day: u8,
+    pub title: &'static str,
+    part_one: SolveFn,
+    part_two: SolveFn,
+}
+
+impl Puzzle {
+    const fn new<S: Solver>(day: u8, title: &'static str) -> Self {
+        Self {
+            day,
+            title,
+            part_one: solve_part_one::<S>,
+            part_two: solve_part_two::<S>,
+        }
+    }
+
+    pub fn solve(&self, part: Part, input: &str) -> Result<String, SolverError> {
+        match part {
+            Part::One => (self.part_one)(input),
+            Part::Two => (self.part_two)(input),
+        }
+    }
+}
+
+fn solve_part_one<S: Solver>(input: &str) -> Result<String, SolverError> {
+    let input = S::parse(input)?;
+
+    Ok(S::part_one(&input)?.to_string())
+}
+
+fn solve_part_two<S: Solver>(input: &str) -> Result<String, SolverError> {
+    let input = S::parse(input)?;
+
+    Ok(S::part_two(&input)?.to_string())
+}
+
+/// Every implemented day, ordered by day number.
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<days::day_1::Day1>(1, "Trebuchet?!"),
+    Puzzle::new::<days::day_2::Day2>(2, "Cube Conundrum"),
+    Puzzle::new::<days::day_3::Day3>(3, "Gear Ratios"),
+    Puzzle::new::<days::day_4::Day4>(4, "Scratchcards"),
+    Puzzle::new::<days::day_5::Day5>(5, "If You Give A Seed A Fertilizer"),
+    Puzzle::new::<days::day_6::Day6>(6, "Wait For It"),
+    Puzzle::new::<days::day_7::Day7>(7, "Camel Cards"),
+    Puzzle::new::<days::day_8::Day8>(8, "Haunted Wasteland"),
+];
+
+pub fn puzzle(day: u8) -> Result<&'static Puzzle, SolverError> {
+    PUZZLES
+        .iter()
+        .find(|p| p.day == day)
+        .ok_or(SolverError::UnknownDay(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzles_ordered_and_unique() {
+        for pair in PUZZLES.windows(2) {
+            assert!(pair[0].day < pair[1].day);
+        }
+    }
+
+    #[test]
+    fn test_puzzle_solve() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+        let day_1 = puzzle(1).expect("Day should be registered");
+
+        assert_eq!(day_1.solve(Part::One, input).expect("Should solve"), "142");
+        assert!(matches!(puzzle(25), Err(SolverError::UnknownDay(25))));
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!("1".parse::<Part>().expect("Should parse"), Part::One);
+        assert_eq!("2".parse::<Part>().expect("Should parse"), Part::Two);
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,47 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+#[test]
+fn test_aoc_input_file() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["7", "1", "resources/day_7.txt"])
+        .output()
+        .expect("Binary should run");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "251106089\n");
+}
+
+#[test]
+fn test_aoc_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["4", "1"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Binary should run");
+
+    child
+        .stdin
+        .take()
+        .expect("Stdin should be piped")
+        .write_all(b"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n")
+        .expect("Should write input");
+
+    let output = child.wait_with_output().expect("Binary should finish");
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "8\n");
+}
+
+#[test]
+fn test_aoc_unknown_day() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["25", "1", "resources/day_7.txt"])
+        .output()
+        .expect("Binary should run");
+
+    assert!(!output.status.success());
+}
@@ -1,42 +1,38 @@
-use std::{fs, str::FromStr};
+use std::fs;
 
-use adventofcode2023::{callibration_value::CalibrationValue};
+use adventofcode2023::{callibration_value::CalibrationValue, days::day_1::Day1, solver::Solver};
 
 #[test]
 fn test_day_1_part_1() {
     let file = fs::read_to_string("resources/day_1.txt").expect("File should be available");
 
-    let mut total: usize = 0;
+    let input = Day1::parse(&file).expect("Should parse input");
 
-    for line in file.lines() {
-        if line.is_empty() {
-            continue;
-        }
-
-        let value = CalibrationValue::from_str(line).expect("Should parse the input line");
-
-        total += value.value;
-    }
-
-    assert_eq!(total, 55712);
+    assert_eq!(Day1::part_one(&input).expect("Should solve"), 55712);
 }
 
 #[test]
 fn test_day_1_part_2() {
     let file = fs::read_to_string("resources/day_1.txt").expect("File should be available");
 
-    let mut total: usize = 0;
+    let input = Day1::parse(&file).expect("Should parse input");
 
-    for line in file.lines() {
-        if line.is_empty() {
-            continue;
-        }
-
-        let value = CalibrationValue::new_v2(line.to_string());
+    assert_eq!(Day1::part_two(&input).expect("Should solve"), 55413);
+}
 
-        total += value.value;
-    }
+#[test]
+fn test_day_1_line_without_number() {
+    let input = Day1::parse("1abc2\nabcdef\n").expect("Should parse input");
 
-    assert_eq!(total, 55413);
+    assert!(Day1::part_one(&input).is_err());
+    assert!(Day1::part_two(&input).is_err());
 }
 
+#[test]
+fn test_day_1_calibration_value_digits() {
+    let value = CalibrationValue::try_new_v2("two1nine".to_owned()).expect("Should find digits");
+
+    assert_eq!(value.raw_value(), "two1nine");
+    assert_eq!(value.string_value(), "29");
+    assert_eq!(value.value, 29);
+}
@@ -1,43 +1,21 @@
-use std::{fs, str::FromStr};
+use std::fs;
 
-use adventofcode2023::cube_game::CubeGame;
+use adventofcode2023::{days::day_2::Day2, solver::Solver};
 
 #[test]
-fn test_day_1_part_1() {
+fn test_day_2_part_1() {
     let file = fs::read_to_string("resources/day_2.txt").expect("File should be available");
 
-    let mut games: Vec<CubeGame> = vec![];
+    let input = Day2::parse(&file).expect("Should parse input");
 
-    for (_i, line) in file.lines().enumerate() {
-        if line.is_empty() {
-            continue;
-        }
-        let game = CubeGame::from_str(line).expect("Should parse input");
-
-        games.push(game);
-    }
-    assert_eq!(games.len(), 100);
-
-    let game = &games[75];
-    println!(
-        "red: {}, blue: {}, green: {}",
-        game.max_red, game.max_blue, game.max_green
-    );
-
-    let total_indexes_of_valid_games = games
-        .iter()
-        .enumerate()
-        .filter(|(_i, g)| {
-            let invalid = g.max_red > 12 || g.max_blue > 14 || g.max_green > 13;
-
-            !invalid
-        })
-        .map(|(i, _)| i + 1)
-        .sum::<usize>();
+    assert_eq!(Day2::part_one(&input).expect("Should solve"), 2006);
+}
 
-    assert_eq!(total_indexes_of_valid_games, 2006);
+#[test]
+fn test_day_2_part_2() {
+    let file = fs::read_to_string("resources/day_2.txt").expect("File should be available");
 
-    let sum_power_of_games = games.iter().map(|g| g.power()).sum::<usize>();
+    let input = Day2::parse(&file).expect("Should parse input");
 
-    assert_eq!(sum_power_of_games, 84911);
+    assert_eq!(Day2::part_two(&input).expect("Should solve"), 84911);
 }
@@ -1,79 +1,31 @@
-use std::{fs, vec};
+use std::fs;
 
-use adventofcode2023::utils::split_whitespace_and_parse_to_usize;
+use adventofcode2023::{days::day_6::Day6, solver::Solver};
 
 #[test]
 fn test_day_6_part_1() {
     let file = fs::read_to_string("resources/day_6.txt").expect("File should be available");
 
-    let mut parsed: Vec<Vec<usize>> = vec![];
+    let input = Day6::parse(&file).expect("Should parse input");
 
-    for line in file.lines() {
-        let (_, values) = line.split_once(": ").expect("Should be splittable");
-
-        let values = values.trim();
-
-        parsed.push(split_whitespace_and_parse_to_usize(values).expect("Should be ok"));
-    }
-
-    println!("{:?}", parsed);
-
-    let times = parsed.get(0).expect("Should be there");
-    let distances = parsed.get(1).expect("Should be there");
-
-    let mut result = 1;
-
-    for (i, time) in times.iter().enumerate() {
-        let distance = distances[i];
-
-        result *= calculate(*time, distance);
-    }
-
-    assert_eq!(result, 170000);
+    assert_eq!(Day6::part_one(&input).expect("Should solve"), 170000);
 }
 
 #[test]
 fn test_day_6_part_2() {
     let file = fs::read_to_string("resources/day_6.txt").expect("File should be available");
 
-    let mut parsed: Vec<Vec<usize>> = vec![];
-
-    for line in file.lines() {
-        let (_, values) = line.split_once(": ").expect("Should be splittable");
-
-        let values = values.replace(' ', "");
+    let input = Day6::parse(&file).expect("Should parse input");
 
-        parsed.push(split_whitespace_and_parse_to_usize(&values).expect("Should be ok"));
-    }
-
-    println!("{:?}", parsed);
-
-    let times = parsed.get(0).expect("Should be there");
-    let distances = parsed.get(1).expect("Should be there");
-
-    let mut result = 1;
-
-    for (i, time) in times.iter().enumerate() {
-        let distance = distances[i];
-
-        result *= calculate(*time, distance);
-    }
-
-    assert_eq!(result, 20537782);
+    assert_eq!(Day6::part_two(&input).expect("Should solve"), 20537782);
 }
 
-fn calculate(time: usize, distance: usize) -> usize {
-    let _speed = 0;
-
-    let mut times: usize = 0;
-
-    for speed in 1..time {
-        let r = speed * (time - speed);
-
-        if r > distance {
-            times += 1;
-        }
-    }
+#[test]
+fn test_day_6_kerned_race_too_large() {
+    // Every race fits in a u64, but the joined distance has 22 digits.
+    let input = Day6::parse("Time: 7 15 30 4000000000\nDistance: 9 40 200 1000000000000000\n")
+        .expect("Should parse input");
 
-    times
+    assert!(Day6::part_one(&input).expect("Should solve") > 0);
+    assert!(Day6::part_two(&input).is_err());
 }
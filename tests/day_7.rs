@@ -1,45 +1,43 @@
 use std::fs;
 
-use adventofcode2023::camel_hand::{CamelHand, GameType};
+use adventofcode2023::{
+    camel_hand::{CamelTable, GameType},
+    days::day_7::Day7,
+    solver::Solver,
+};
 
 #[test]
 fn test_day_7_part_1() {
     let file = fs::read_to_string("resources/day_7.txt").expect("File should be available");
 
-    let mut hands: Vec<CamelHand> = file
-        .lines()
-        .map(|s| CamelHand::try_from((s, GameType::WithoutJoker)))
-        .collect::<Result<Vec<_>, _>>()
-        .expect("Should parse");
+    let input = Day7::parse(&file).expect("Should parse input");
 
-    hands.sort();
-
-    let result: usize = hands
-        .iter()
-        .enumerate()
-        .map(|(i, hand)| (i + 1) * hand.bid)
-        .sum();
-
-    assert_eq!(result, 251106089);
+    assert_eq!(Day7::part_one(&input).expect("Should solve"), 251106089);
 }
 
 #[test]
 fn test_day_7_part_2() {
     let file = fs::read_to_string("resources/day_7.txt").expect("File should be available");
 
-    let mut hands: Vec<CamelHand> = file
-        .lines()
-        .map(|s| CamelHand::try_from((s, GameType::WithJoker)))
-        .collect::<Result<Vec<_>, _>>()
-        .expect("Should parse");
+    let input = Day7::parse(&file).expect("Should parse input");
 
-    hands.sort();
+    assert_eq!(Day7::part_two(&input).expect("Should solve"), 249620106);
+}
 
-    let result: usize = hands
-        .iter()
-        .enumerate()
-        .map(|(i, hand)| (i + 1) * hand.bid)
-        .sum();
+#[test]
+fn test_day_7_unambiguous_ranking() {
+    let file = fs::read_to_string("resources/day_7.txt").expect("File should be available");
 
-    assert_eq!(result, 249620106);
+    for game_type in [GameType::WithoutJoker, GameType::WithJoker] {
+        let table = CamelTable::try_from((file.as_str(), game_type)).expect("Should parse input");
+
+        assert_eq!(table.hands().len(), 1000);
+        assert!(table.ties().is_empty());
+    }
+}
+
+#[test]
+fn test_day_7_invalid_input() {
+    assert!(Day7::parse("32T3K 765\nKK67 28\n").is_err());
+    assert!(Day7::parse("32T3K x\n").is_err());
 }
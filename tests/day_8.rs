@@ -1,11 +1,21 @@
 use std::fs;
 
-use adventofcode2023::network::Network;
+use adventofcode2023::{days::day_8::Day8, solver::Solver};
+
 #[test]
 fn test_day_8_part_1() {
     let file = fs::read_to_string("resources/day_8.txt").expect("File should be available");
 
-    let network: Network = file.try_into().expect("Should parse");
+    let input = Day8::parse(&file).expect("Should parse input");
+
+    assert_eq!(Day8::part_one(&input).expect("Should find answer"), 20513);
+}
+
+#[test]
+fn test_day_8_part_2() {
+    let file = fs::read_to_string("resources/day_8.txt").expect("File should be available");
+
+    let input = Day8::parse(&file).expect("Should parse input");
 
-    assert_eq!(network.walk().expect("Should find answer"), 0);
+    assert_eq!(Day8::part_two(&input).expect("Should find answer"), 15995167053923);
 }